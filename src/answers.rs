@@ -0,0 +1,129 @@
+//! Expected answers, kept in `answers.txt` so refactors can be checked against known results.
+//!
+//! Every non-empty line that is not a `#` comment holds the input file, part and answer:
+//!
+//! ```text
+//! inputs/day_07_camel_cards.txt 1 250370104
+//! ```
+//!
+//! Input paths are kept relative to the crate root, so `./inputs/…` and `inputs/…` find the same answers.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::env;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use crate::registry::Part;
+
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    entries: BTreeMap<(String, Part), String>,
+}
+
+impl Answers {
+    /// Loads answers from a file. A missing file means no answers were recorded yet.
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse().map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, input: &str, part: Part) -> Option<&str> {
+        self.entries.get(&(key(input), part)).map(|a| a.as_str())
+    }
+
+    pub fn record(&mut self, input: &str, part: Part, answer: &str) {
+        self.entries.insert((key(input), part), answer.to_string());
+    }
+}
+
+/// The input path relative to the current directory, which is the crate root, and without `.` components.
+fn key(input: &str) -> String {
+    let path = Path::new(input);
+    let relative = env::current_dir().ok()
+        .and_then(|dir| path.strip_prefix(dir).ok())
+        .unwrap_or(path);
+    relative.components().filter(|c| *c != Component::CurDir).collect::<PathBuf>().display().to_string()
+}
+
+impl std::str::FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.splitn(3, ' ').collect();
+            let part = match fields.get(1) {
+                Some(&"1") => Part::One,
+                Some(&"2") => Part::Two,
+                _ => return Err(format!("line {}: expected `<input> <part> <answer>`", i + 1)),
+            };
+            match fields.get(2) {
+                Some(answer) => answers.record(fields[0], part, answer.trim()),
+                None => return Err(format!("line {}: missing answer", i + 1)),
+            }
+        }
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# <input> <part> <answer>")?;
+        for ((input, part), answer) in &self.entries {
+            writeln!(f, "{} {} {}", input, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.record("inputs/day_07_camel_cards.txt", Part::Two, "251735672");
+        answers.record("inputs/day_07_camel_cards.txt", Part::One, "250370104");
+
+        let text = answers.to_string();
+        assert_eq!(text, "# <input> <part> <answer>\n\
+                          inputs/day_07_camel_cards.txt 1 250370104\n\
+                          inputs/day_07_camel_cards.txt 2 251735672\n");
+        assert_eq!(text.parse(), Ok(answers));
+    }
+
+    #[test]
+    fn test_equivalent_paths() {
+        let mut answers = Answers::default();
+        answers.record("./inputs/day_07_camel_cards.txt", Part::One, "250370104");
+        assert_eq!(answers.get("inputs/day_07_camel_cards.txt", Part::One), Some("250370104"));
+
+        let absolute = env::current_dir().unwrap().join("inputs/./day_07_camel_cards.txt");
+        assert_eq!(answers.get(&absolute.display().to_string(), Part::One), Some("250370104"));
+        assert_eq!(answers.get("-", Part::One), None);
+        assert!(answers.to_string().contains("\ninputs/day_07_camel_cards.txt 1"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!("inputs/day_01_trebuchet.txt 3 42".parse::<Answers>().is_err());
+        assert!("inputs/day_01_trebuchet.txt 1".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,221 @@
+//! Benchmarks of parsing and both parts of a day, timed separately.
+//!
+//! Timings can be saved as a JSON baseline, mapping day name to phase and nanoseconds,
+//! and later runs compared against it to spot regressions.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Day, Part};
+use crate::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part(Part::One), Phase::Part(Part::Two)];
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "part 1" => Ok(Phase::Part(Part::One)),
+            "part 2" => Ok(Phase::Part(Part::Two)),
+            _ => Err(format!("Unknown phase: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    /// Runs whose timings are thrown away, to warm up caches and the allocator
+    pub warmup: usize,
+    /// Runs whose timings are measured; must be at least 1
+    pub runs: usize,
+}
+
+/// Median duration of every measured phase.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Timings(pub BTreeMap<Phase, Duration>);
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.0.values().sum()
+    }
+}
+
+pub fn bench_day(day: &Day, input: &str, parts: &[Part], options: &BenchOptions) -> Result<Timings, ParseError> {
+    let mut samples: BTreeMap<Phase, Vec<Duration>> = BTreeMap::new();
+
+    for run in 0..options.warmup + options.runs {
+        let start = Instant::now();
+        let puzzle = day.parse(input)?;
+        let mut run_samples = vec![(Phase::Parse, start.elapsed())];
+
+        for &part in parts {
+            let start = Instant::now();
+            black_box(puzzle.solve(part));
+            run_samples.push((Phase::Part(part), start.elapsed()));
+        }
+
+        if run >= options.warmup {
+            for (phase, duration) in run_samples {
+                samples.entry(phase).or_default().push(duration);
+            }
+        }
+    }
+
+    Ok(Timings(samples.into_iter().map(|(phase, durations)| (phase, median(durations))).collect()))
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
+
+/// Timings of earlier runs by day name.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline(pub BTreeMap<String, Timings>);
+
+impl Baseline {
+    pub fn load(path: &Path) -> io::Result<Baseline> {
+        let json: BTreeMap<String, BTreeMap<String, u64>> = serde_json::from_str(&fs::read_to_string(path)?)?;
+
+        let mut baseline = Baseline::default();
+        for (name, phases) in json {
+            let mut timings = Timings::default();
+            for (phase, nanos) in phases {
+                let phase = phase.parse().map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                timings.0.insert(phase, Duration::from_nanos(nanos));
+            }
+            baseline.0.insert(name, timings);
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json: BTreeMap<&str, BTreeMap<String, u64>> = self.0.iter()
+            .map(|(name, timings)| (name.as_str(), timings.0.iter()
+                .map(|(phase, duration)| (phase.to_string(), duration.as_nanos() as u64))
+                .collect()))
+            .collect();
+        fs::write(path, serde_json::to_string_pretty(&json)?)
+    }
+}
+
+/// Differences below this are noise, no matter how large they are relatively.
+const MIN_REGRESSION: Duration = Duration::from_micros(50);
+
+/// Whether `current` is slower than `baseline` by more than `threshold` (0.1 = 10%).
+pub fn is_regression(baseline: Duration, current: Duration, threshold: f64) -> bool {
+    current > baseline + MIN_REGRESSION && current.as_secs_f64() > baseline.as_secs_f64() * (1.0 + threshold)
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+/// Renders timings as a table, one day per row. Cells slower than the baseline are marked with `!`.
+/// Returns the table and whether any regression was found.
+pub fn format_table(rows: &[(String, Timings)], baseline: Option<&Baseline>, threshold: f64) -> (String, bool) {
+    let mut table = format!("{:<28}", "Day");
+    for phase in Phase::ALL {
+        table += &format!("{:>22}", phase.to_string());
+    }
+    table += &format!("{:>14}\n", "total");
+
+    let mut any_regression = false;
+    let mut total = Duration::ZERO;
+    for (name, timings) in rows {
+        table += &format!("{:<28}", name);
+        for phase in Phase::ALL {
+            let cell = match timings.0.get(&phase) {
+                None => "-".to_string(),
+                Some(&current) => {
+                    match baseline.and_then(|b| b.0.get(name)).and_then(|t| t.0.get(&phase)) {
+                        None => format_duration(current),
+                        Some(&before) => {
+                            let change = (current.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+                            let regression = is_regression(before, current, threshold);
+                            any_regression |= regression;
+                            format!("{} {:+4.0}%{}", format_duration(current), change, if regression { "!" } else { " " })
+                        }
+                    }
+                }
+            };
+            table += &format!("{:>22}", cell);
+        }
+        table += &format!("{:>14}\n", format_duration(timings.total()));
+        total += timings.total();
+    }
+    table += &format!("{:<28}{:>80}\n", "Total", format_duration(total));
+
+    (table, any_regression)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_regression() {
+        let ms = Duration::from_millis;
+        assert!(is_regression(ms(10), ms(12), 0.1));
+        assert!(!is_regression(ms(10), ms(11), 0.1));
+        assert!(!is_regression(ms(10), ms(5), 0.1));
+        // Too small to matter
+        assert!(!is_regression(Duration::from_nanos(100), Duration::from_nanos(300), 0.1));
+    }
+
+    #[test]
+    fn test_median() {
+        let ms = Duration::from_millis;
+        assert_eq!(median(vec![ms(3), ms(1), ms(100)]), ms(3));
+        assert_eq!(median(vec![ms(7)]), ms(7));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_340)), "12.34 µs");
+        assert_eq!(format_duration(Duration::from_millis(5)), "5.00 ms");
+        assert_eq!(format_duration(Duration::from_millis(1500)), "1.50 s");
+    }
+
+    #[test]
+    fn test_phase_round_trip() {
+        for phase in Phase::ALL {
+            assert_eq!(phase.to_string().parse(), Ok(phase));
+        }
+    }
+}
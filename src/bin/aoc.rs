@@ -0,0 +1,777 @@
+//! Runner for all days of Advent of Code 2023
+//! https://adventofcode.com/2023
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::{Command as Process, ExitCode, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use clap::builder::RangedU64ValueParser;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use log::LevelFilter;
+use rayon::prelude::*;
+
+use advent_of_code_2022::answers::{Answers, ANSWERS_FILE};
+use advent_of_code_2022::bench::{bench_day, format_table, BenchOptions, Baseline};
+use advent_of_code_2022::client::{fetch_input, Client, Fetched, DEFAULT_BASE_URL, THROTTLE_FILE};
+use advent_of_code_2022::examples::{self, examples_by_part};
+use advent_of_code_2022::input::{expand_inputs, read_input, InputKind, STDIN};
+use advent_of_code_2022::params::{parse_override, Overrides, ParamInfo};
+use advent_of_code_2022::registry::{find_day, Day, Part, DAYS};
+use advent_of_code_2022::report::{self, format_calendar, Report};
+use advent_of_code_2022::scaffold::new_day;
+use advent_of_code_2022::submit::{submit_answer, Rejected, Submission, Verdict, REJECTED_FILE};
+use advent_of_code_2022::watch::{diff_answers, watched_files, Snapshot};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs solutions of Advent of Code 2023")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Log intermediate results of the solvers to stderr: -v for debug, -vv for trace.
+    /// RUST_LOG picks days by module, e.g. RUST_LOG=advent_of_code_2022::days::day_12_hot_springs=trace
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve with the puzzle input from `inputs/`
+    Run(RunArgs),
+    /// Solve with the example input from `test_inputs/`
+    Test(RunArgs),
+    /// Time parsing and both parts with the puzzle input from `inputs/`
+    Bench(BenchArgs),
+    /// Download puzzle inputs to `inputs/`, skipping those downloaded already
+    Fetch(FetchArgs),
+    /// Solve one part with the puzzle input and submit the answer
+    Submit(SubmitArgs),
+    /// Create the module and input files of a new day, and register it
+    New(NewArgs),
+    /// Save the examples and their answers from a saved puzzle page to `test_inputs/` and `answers.txt`
+    Examples(ExamplesArgs),
+    /// Rebuild and re-run a day with the example and the puzzle input whenever its source or inputs change
+    Watch(WatchArgs),
+    /// List the parameters of days, with their defaults
+    Params(ParamsArgs),
+    /// Print what a day makes of its puzzle input, to see how the answers come about
+    Explain(ExplainArgs),
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Day number, e.g. 7
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Run every registered day, in parallel
+    #[arg(long)]
+    all: bool,
+
+    /// Solve only this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Save the answers to `answers.txt` as the expected ones
+    #[arg(long, conflicts_with = "param")]
+    record: bool,
+
+    /// Override a parameter of the day, e.g. expansion_2=10; `aoc params` lists them
+    #[arg(long, value_name = "NAME=VALUE", value_parser = parse_override)]
+    param: Vec<(String, String)>,
+
+    /// Input files to solve instead of the usual one, each in turn: `-` for stdin, or directories of `.txt` files
+    #[arg(long, short, value_name = "PATH", num_args = 1.., conflicts_with = "all")]
+    input: Vec<PathBuf>,
+
+    /// Seconds parsing and each part may take. Only code that calls `cancel::checkpoint()` stops then,
+    /// like parsing with `parse_all` and the solvers of days 8 and 12; other solvers run until they finish
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+
+    /// How to print the results; debug output of the solvers goes to stderr either way
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Answers as lines of text
+    Text,
+    /// One JSON object per day, with its answers, timings and the checksum of its input
+    Json,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Runs before measuring starts
+    #[arg(long, default_value_t = 3)]
+    warmup: usize,
+
+    /// Measured runs; the median is reported
+    #[arg(long, default_value_t = 10, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    runs: usize,
+
+    /// Save timings as a JSON baseline
+    #[arg(long, value_name = "FILE")]
+    save_baseline: Option<PathBuf>,
+
+    /// Compare timings with a JSON baseline saved earlier
+    #[arg(long, value_name = "FILE")]
+    baseline: Option<PathBuf>,
+
+    /// Slowdown relative to the baseline reported as a regression, in percent
+    #[arg(long, default_value_t = 20.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct SiteArgs {
+    /// Session cookie of a browser logged in to Advent of Code
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: String,
+
+    /// Address of the Advent of Code website, e.g. of a local stub server
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// Seconds to wait between requests, also across runs
+    #[arg(long, value_name = "SECONDS", default_value = "5", value_parser = parse_seconds)]
+    min_interval: Duration,
+}
+
+impl SiteArgs {
+    fn client(&self) -> Client {
+        Client::new(&self.base_url, &self.session)
+            .with_min_interval(self.min_interval)
+            .with_throttle_file(Path::new(THROTTLE_FILE))
+    }
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day numbers, e.g. 7
+    #[arg(required = true)]
+    days: Vec<u8>,
+
+    #[command(flatten)]
+    site: SiteArgs,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day number, e.g. 7
+    day: u8,
+
+    /// Part to submit
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    #[command(flatten)]
+    site: SiteArgs,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day number, e.g. 14
+    day: u8,
+
+    /// Title in snake_case, e.g. parabolic_reflector_dish
+    title: String,
+}
+
+#[derive(Args)]
+struct ExamplesArgs {
+    /// Day number, e.g. 14
+    day: u8,
+
+    /// Puzzle page saved from the browser
+    page: PathBuf,
+
+    /// Replace example files that have other contents
+    #[arg(long)]
+    overwrite: bool,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Day number, e.g. 14
+    day: u8,
+
+    /// Seconds between checks for changes
+    #[arg(long, value_name = "SECONDS", default_value = "0.5", value_parser = parse_seconds)]
+    interval: Duration,
+
+    /// Seconds parsing and each part may take, for code that calls `cancel::checkpoint()` (see `aoc run --help`)
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+}
+
+#[derive(Args)]
+struct ParamsArgs {
+    /// Day number, e.g. 11; all days if missing
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct ExplainArgs {
+    /// Day number, e.g. 1
+    day: u8,
+
+    /// Explain the example input from `test_inputs/` instead of the puzzle input
+    #[arg(long)]
+    example: bool,
+
+    /// Input file to explain instead, or `-` for stdin
+    #[arg(long, short, value_name = "PATH", conflicts_with = "example")]
+    input: Option<PathBuf>,
+
+    /// Only what makes the answers of the parts differ, like the lines of day 1 whose values differ
+    #[arg(long)]
+    disagree: bool,
+
+    /// Override a parameter of the day, e.g. words=german; `aoc params` lists them
+    #[arg(long, value_name = "NAME=VALUE", value_parser = parse_override)]
+    param: Vec<(String, String)>,
+}
+
+/// Reads a number of seconds like `0.5`, rejecting negative, infinite and NaN ones.
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    let seconds: f64 = s.parse().map_err(|e: std::num::ParseFloatError| e.to_string())?;
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("expected finite seconds of 0 or more, got {}", s))
+}
+
+impl Selection {
+    fn days(&self) -> Result<Vec<&'static Day>, String> {
+        match self.day {
+            Some(number) => match find_day(number) {
+                Some(day) => Ok(vec![day]),
+                None => Err(format!("Day {} is not solved yet", number)),
+            },
+            None => Ok(DAYS.iter().collect()),
+        }
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(1) => vec![Part::One],
+            Some(2) => vec![Part::Two],
+            _ => Part::BOTH.to_vec(),
+        }
+    }
+}
+
+/// Runs a day and prints its answers, or why it has none.
+fn run_day(day: &Day, path: &Path, parts: &[Part], timeout: Option<Duration>, overrides: &Overrides, format: Format)
+           -> Report {
+    let report = report::run_day(day, path, parts, timeout, overrides);
+    match format {
+        Format::Text => {
+            println!("== {} ({})", day.name(), report.input.display());
+            for (part, answer) in &report.answers {
+                println!("Result [part {}]: {}", part, answer);
+            }
+            if let Some(e) = &report.error {
+                eprintln!("{}", e);
+            }
+        }
+        Format::Json => println!("{}", report.to_json()),
+    }
+    report
+}
+
+/// The inputs to solve `parts` of a day with, each with the parts it is for. Part 2 of the examples has a file
+/// of its own when its example differs from the one of part 1.
+fn inputs_by_part(day: &Day, kind: InputKind, parts: &[Part]) -> Vec<(PathBuf, Vec<Part>)> {
+    match kind {
+        InputKind::Example => examples_by_part(&kind.path(day), parts),
+        InputKind::Real => vec![(kind.path(day), parts.to_vec())],
+    }
+}
+
+/// Runs days in parallel and prints them as a calendar, once all are done. Days that fail do not stop the others.
+fn run_all(days: &[&Day], kind: InputKind, parts: &[Part], timeout: Option<Duration>, overrides: &Overrides,
+           format: Format, expected: &Answers) -> Vec<Report> {
+    let start = Instant::now();
+    let reports: Vec<Report> = days.par_iter()
+        .flat_map(|day| inputs_by_part(day, kind, parts).into_par_iter()
+            .map(|(path, parts)| report::run_day(day, &path, &parts, timeout, overrides)))
+        .collect();
+    let wall_clock = start.elapsed();
+
+    match format {
+        Format::Text => {
+            print!("{}", format_calendar(&reports, expected, wall_clock));
+            for e in reports.iter().filter_map(|r| r.error.as_ref()) {
+                eprintln!("{}", e);
+            }
+        }
+        Format::Json => {
+            for report in &reports {
+                println!("{}", report.to_json());
+            }
+        }
+    }
+    reports
+}
+
+/// The parameters given on the command line, checked against those of the days.
+fn overrides(param: &[(String, String)], days: &[&Day]) -> Result<Overrides, String> {
+    let mut overrides = Overrides::default();
+    for (name, value) in param {
+        overrides.set(name, value);
+    }
+    let params: Vec<&dyn ParamInfo> = days.iter().flat_map(|day| day.params.iter().copied()).collect();
+    overrides.check(&params)?;
+    Ok(overrides)
+}
+
+fn run(args: &RunArgs, kind: InputKind) -> ExitCode {
+    let days = match args.selection.days() {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts = args.selection.parts();
+    let mut recorded = Answers::load(Path::new(ANSWERS_FILE)).expect("Cannot load answers");
+    let timeout = args.timeout;
+    let inputs = match expand_inputs(&args.input) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let overrides = match overrides(&args.param, &days) {
+        Ok(overrides) => overrides,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let reports = match (args.selection.all, inputs.is_empty()) {
+        (true, _) => run_all(&days, kind, &parts, timeout, &overrides, args.format, &recorded),
+        (false, true) => days.iter()
+            .flat_map(|day| inputs_by_part(day, kind, &parts).into_iter()
+                .map(|(path, parts)| run_day(day, &path, &parts, timeout, &overrides, args.format)))
+            .collect(),
+        // Without --all there is a single day
+        (false, false) => inputs.iter()
+            .map(|path| run_day(days[0], path, &parts, timeout, &overrides, args.format))
+            .collect(),
+    };
+
+    if args.record {
+        // Answers for the standard input could not be checked again
+        for report in reports.iter().filter(|r| r.is_ok() && r.input != Path::new(STDIN)) {
+            for (part, answer) in &report.answers {
+                recorded.record(&report.input.display().to_string(), *part, answer);
+            }
+        }
+        recorded.save(Path::new(ANSWERS_FILE)).expect("Cannot save answers");
+        eprintln!("Answers saved to {}", ANSWERS_FILE);
+    }
+
+    match reports.iter().all(|r| r.is_ok()) {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+fn bench(args: &BenchArgs) -> ExitCode {
+    let days = match args.selection.days() {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let baseline = match &args.baseline {
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("Cannot load baseline {}: {}", path.display(), e);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let parts = args.selection.parts();
+    let options = BenchOptions { warmup: args.warmup, runs: args.runs };
+    let mut all_ok = true;
+    let mut rows = vec![];
+    for day in days {
+        let path = InputKind::Real.path(day);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) => {
+                eprintln!("Cannot read {}: {}", path.display(), e);
+                all_ok = false;
+                continue;
+            }
+        };
+
+        match panic::catch_unwind(|| bench_day(day, &contents, &parts, &options)) {
+            Ok(Ok(timings)) => rows.push((day.name(), timings)),
+            Ok(Err(e)) => {
+                eprintln!("{}", e.with_file(&path));
+                all_ok = false;
+            }
+            Err(_) => {
+                eprintln!("{} failed", day.name());
+                all_ok = false;
+            }
+        }
+    }
+
+    let (table, any_regression) = format_table(&rows, baseline.as_ref(), args.threshold / 100.0);
+    print!("{}", table);
+    if any_regression {
+        println!("! slower than the baseline by more than {}%", args.threshold);
+    }
+
+    if let Some(path) = &args.save_baseline {
+        let mut saved = Baseline::load(path).unwrap_or_default();
+        saved.0.extend(rows);
+        if let Err(e) = saved.save(path) {
+            eprintln!("Cannot save baseline {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+        println!("Baseline saved to {}", path.display());
+    }
+
+    match all_ok && !any_regression {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+fn fetch(args: &FetchArgs) -> ExitCode {
+    let mut client = args.site.client();
+    let mut all_ok = true;
+    for &number in &args.days {
+        let Some(path) = InputKind::Real.find_path(number) else {
+            eprintln!("Day {} has no input file yet, create the day with `aoc new` first", number);
+            all_ok = false;
+            continue;
+        };
+
+        match fetch_input(&mut client, number, &path) {
+            Ok(Fetched::Downloaded) => println!("Downloaded {}", path.display()),
+            Ok(Fetched::Cached) => println!("{} exists already, not downloading it again", path.display()),
+            Err(e) => {
+                eprintln!("Cannot fetch input of day {}: {}", number, e);
+                all_ok = false;
+            }
+        }
+    }
+
+    match all_ok {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+fn submit(args: &SubmitArgs) -> ExitCode {
+    let Some(day) = find_day(args.day) else {
+        eprintln!("Day {} is not solved yet", args.day);
+        return ExitCode::FAILURE;
+    };
+    let part = match args.part {
+        1 => Part::One,
+        _ => Part::Two,
+    };
+
+    let report = run_day(day, &InputKind::Real.path(day), &[part], None, &Overrides::default(), Format::Text);
+    let Some((_, answer)) = report.answers.first() else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut rejected = Rejected::load(Path::new(REJECTED_FILE)).expect("Cannot load rejected answers");
+    let submission = match submit_answer(&mut args.site.client(), &mut rejected, day.number, &day.name(), part, answer) {
+        Ok(submission) => submission,
+        Err(e) => {
+            eprintln!("Cannot submit answer: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match submission {
+        Submission::Skipped(reason) => {
+            eprintln!("Not submitting: {}", reason);
+            ExitCode::FAILURE
+        }
+        Submission::Sent(Verdict::Correct) => {
+            println!("{}", Verdict::Correct);
+            let mut recorded = Answers::load(Path::new(ANSWERS_FILE)).expect("Cannot load answers");
+            recorded.record(&InputKind::Real.path(day).display().to_string(), part, answer);
+            recorded.save(Path::new(ANSWERS_FILE)).expect("Cannot save answers");
+            println!("Answer saved to {}", ANSWERS_FILE);
+            ExitCode::SUCCESS
+        }
+        Submission::Sent(verdict) => {
+            println!("{}", verdict);
+            if let Verdict::Incorrect(_) | Verdict::Wait(_) = verdict {
+                rejected.save(Path::new(REJECTED_FILE)).expect("Cannot save rejected answers");
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new(args: &NewArgs) -> ExitCode {
+    match new_day(Path::new("."), args.day, &args.title) {
+        Ok(changed) => {
+            for path in changed {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Cannot create day {}: {}", args.day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn save_examples(args: &ExamplesArgs) -> ExitCode {
+    let Some(path) = InputKind::Example.find_path(args.day) else {
+        eprintln!("Day {} has no example file yet, create the day with `aoc new` first", args.day);
+        return ExitCode::FAILURE;
+    };
+    let page = match fs::read_to_string(&args.page) {
+        Ok(page) => page,
+        Err(e) => {
+            eprintln!("Cannot read {}: {}", args.page.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let found = examples::extract(&page);
+    if found.is_empty() {
+        eprintln!("No puzzle description in {}", args.page.display());
+        return ExitCode::FAILURE;
+    }
+
+    let mut recorded = Answers::load(Path::new(ANSWERS_FILE)).expect("Cannot load answers");
+    match examples::save(&found, &path, &mut recorded, args.overwrite) {
+        Ok(written) => {
+            for line in written {
+                println!("{}", line);
+            }
+            recorded.save(Path::new(ANSWERS_FILE)).expect("Cannot save answers");
+            println!("Answers saved to {}", ANSWERS_FILE);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Cannot save examples of day {}: {}", args.day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Logs of this crate at the level given by `-v`, warnings of dependencies, and whatever RUST_LOG adds.
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    env_logger::Builder::new()
+        .filter_level(LevelFilter::Warn)
+        .filter_module(env!("CARGO_PKG_NAME"), level)
+        .format_timestamp(None)
+        .parse_env("RUST_LOG")
+        .init();
+}
+
+/// Rebuilds the runner with cargo, in the profile of the running one. Cargo reports the errors, if any.
+fn rebuild() -> bool {
+    let mut cargo = Process::new("cargo");
+    cargo.args(["build", "--quiet", "--bin", "aoc"]);
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    cargo.status().is_ok_and(|status| status.success())
+}
+
+/// Runs a day with the rebuilt runner, which prints a JSON report per input: two for the examples when part 2
+/// has one of its own. Logs and panics of the solvers go to stderr.
+fn run_rebuilt(runner: &Path, day: &Day, kind: InputKind, timeout: Option<Duration>) -> Result<Vec<Report>, String> {
+    let command = match kind {
+        InputKind::Example => "test",
+        InputKind::Real => "run",
+    };
+    let mut process = Process::new(runner);
+    process.args([command, &day.number.to_string(), "--format", "json"]).stderr(Stdio::inherit());
+    if let Some(timeout) = timeout {
+        process.args(["--timeout", &timeout.as_secs_f64().to_string()]);
+    }
+
+    let output = process.output().map_err(|e| format!("Cannot run {}: {}", runner.display(), e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let reports = stdout.lines()
+        .map(|line| {
+            let json = serde_json::from_str(line).map_err(|e| format!("Cannot read report: {}", e))?;
+            Report::from_json(&json)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match reports.is_empty() {
+        true => Err(format!("{} printed no report", runner.display())),
+        false => Ok(reports),
+    }
+}
+
+fn watch(args: &WatchArgs) -> ExitCode {
+    let Some(day) = find_day(args.day) else {
+        eprintln!("Day {} is not registered yet, create it with `aoc new` first", args.day);
+        return ExitCode::FAILURE;
+    };
+    // Taken before cargo replaces the executable, which changes what the running process points to
+    let runner = match std::env::current_exe() {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("Cannot find the runner: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let files = watched_files(day);
+    println!("Watching {}", files.iter().map(|f| f.display().to_string()).collect::<Vec<_>>().join(", "));
+    let mut snapshot = Snapshot::default();
+    let mut previous: BTreeMap<PathBuf, Vec<(Part, String)>> = BTreeMap::new();
+    loop {
+        let current = Snapshot::take(&files);
+        let changed = current.changed_since(&snapshot);
+        if !changed.is_empty() {
+            match snapshot == Snapshot::default() {
+                true => println!("\n== Building"),
+                false => println!("\n== {} changed, rebuilding",
+                                  changed.iter().map(|f| f.display().to_string()).collect::<Vec<_>>().join(", ")),
+            }
+            snapshot = current;
+
+            if rebuild() {
+                for kind in [InputKind::Example, InputKind::Real] {
+                    match run_rebuilt(&runner, day, kind, args.timeout) {
+                        Ok(reports) => {
+                            for report in reports {
+                                println!("{} ({})", day.name(), report.input.display());
+                                let before = previous.get(&report.input).map(Vec::as_slice);
+                                for line in diff_answers(before, &report.answers) {
+                                    println!("  {}", line);
+                                }
+                                if let Some(e) = &report.error {
+                                    eprintln!("{}", e);
+                                }
+                                previous.insert(report.input, report.answers);
+                            }
+                        }
+                        Err(e) => eprintln!("{}", e),
+                    }
+                }
+            }
+        }
+        thread::sleep(args.interval);
+    }
+}
+
+fn list_params(args: &ParamsArgs) -> ExitCode {
+    let days = match args.day {
+        Some(number) => match find_day(number) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("Day {} is not solved yet", number);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => DAYS.iter().collect(),
+    };
+
+    for day in days.iter().filter(|day| !day.params.is_empty()) {
+        println!("{}", day.name());
+        for param in day.params {
+            println!("  {:<14}{:>10}  {}", param.name(), param.default_value(), param.help());
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn explain(args: &ExplainArgs) -> ExitCode {
+    let Some(day) = find_day(args.day) else {
+        eprintln!("Day {} is not solved yet", args.day);
+        return ExitCode::FAILURE;
+    };
+    let overrides = match overrides(&args.param, &[day]) {
+        Ok(overrides) => overrides,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let path = match (&args.input, args.example) {
+        (Some(path), _) => path.clone(),
+        (None, true) => InputKind::Example.path(day),
+        (None, false) => InputKind::Real.path(day),
+    };
+    let contents = match read_input(&path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Cannot read {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let explanation = overrides.scope(|| day.parse(&contents).map(|puzzle| puzzle.explain(args.disagree)));
+    match explanation {
+        Ok(Some(explanation)) => {
+            print!("{}", explanation);
+            ExitCode::SUCCESS
+        }
+        Ok(None) => {
+            eprintln!("Day {} has nothing to explain", day.number);
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("{}", e.with_file(&path));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+
+    match &cli.command {
+        Command::Run(args) => run(args, InputKind::Real),
+        Command::Test(args) => run(args, InputKind::Example),
+        Command::Bench(args) => bench(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => new(args),
+        Command::Examples(args) => save_examples(args),
+        Command::Watch(args) => watch(args),
+        Command::Params(args) => list_params(args),
+        Command::Explain(args) => explain(args),
+    }
+}
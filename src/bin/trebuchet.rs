@@ -0,0 +1,109 @@
+//! Day 1 over inputs too big to hold in memory, like generated ones of several gigabytes.
+//! Unlike `aoc run`, the input is read a line or a chunk at a time, from a file or stdin.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use advent_of_code_2022::days::day_01_trebuchet::{
+    calibrate_parallel, calibrate_reader, explain_reader, DigitScanner, Trebuchet,
+};
+use advent_of_code_2022::input::STDIN;
+use advent_of_code_2022::params::{parse_override, Overrides};
+use advent_of_code_2022::registry::Part;
+use advent_of_code_2022::Solution;
+
+#[derive(Parser)]
+#[command(name = "trebuchet", about = "Solves day 1 of Advent of Code 2023 from a stream")]
+struct Cli {
+    /// Input file, or `-` for stdin
+    #[arg(default_value = STDIN)]
+    input: PathBuf,
+
+    /// Solve only this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Solve chunks of lines in parallel
+    #[arg(long)]
+    parallel: bool,
+
+    /// Print the digits found in each line instead of solving, like `aoc explain 1`
+    #[arg(long, conflicts_with_all = ["part", "parallel"])]
+    explain: bool,
+
+    /// Only the lines whose value differs between the parts, with --explain
+    #[arg(long, requires = "explain")]
+    disagree: bool,
+
+    /// Size of the chunks in bytes, with --parallel
+    #[arg(long, default_value_t = 1 << 20, value_parser = clap::value_parser!(u64).range(1..))]
+    chunk_size: u64,
+
+    /// Override a parameter of day 1, e.g. words=german
+    #[arg(long, value_name = "NAME=VALUE", value_parser = parse_override)]
+    param: Vec<(String, String)>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let mut overrides = Overrides::default();
+    for (name, value) in &cli.param {
+        overrides.set(name, value);
+    }
+    if let Err(e) = overrides.check(Trebuchet::PARAMS) {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+
+    let parts = match cli.part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => Part::BOTH.to_vec(),
+    };
+    // The word list was read and checked with the parameters
+    let scanners: Vec<DigitScanner> =
+        overrides.scope(|| parts.iter().map(|&part| DigitScanner::for_part(part)).collect());
+
+    let reader: Box<dyn BufRead> = match cli.input.to_str() == Some(STDIN) {
+        true => Box::new(io::stdin().lock()),
+        false => match File::open(&cli.input) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(e) => {
+                eprintln!("Cannot read {}: {}", cli.input.display(), e);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    if cli.explain {
+        return match overrides.scope(|| explain_reader(reader, &mut io::stdout().lock(), cli.disagree)) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("Cannot explain {}: {}", cli.input.display(), e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let sums = match cli.parallel {
+        true => calibrate_parallel(reader, &scanners, cli.chunk_size as usize),
+        false => calibrate_reader(reader, &scanners),
+    };
+    match sums {
+        Ok(sums) => {
+            for (part, sum) in parts.iter().zip(sums) {
+                println!("Result [part {}]: {}", part, sum);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Cannot read {}: {}", cli.input.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Cooperative cancellation of solvers that run for too long.
+//!
+//! The runner installs a [`CancelToken`] for the thread that solves a part, and a [`Watchdog`] cancels it
+//! once the time budget is spent. Solvers call [`checkpoint`] in their hot loops; after cancellation it unwinds
+//! with a [`Cancelled`] payload, which the runner tells apart from other panics. Solvers that never call it
+//! simply run to the end.
+
+use std::cell::RefCell;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// Payload of the unwinding started by [`checkpoint`] after cancellation.
+#[derive(Debug)]
+pub struct Cancelled;
+
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// The token installed for this thread, or one that is never cancelled.
+    /// Solvers pass it on to the threads they start, with [`CancelToken::scope`].
+    pub fn current() -> CancelToken {
+        CURRENT.with(|current| current.borrow().clone()).unwrap_or_default()
+    }
+
+    /// Runs `f` with this token installed for the current thread, restoring the previous one afterwards,
+    /// also when `f` unwinds.
+    pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<CancelToken>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+            }
+        }
+
+        let _restore = Restore(CURRENT.with(|current| current.borrow_mut().replace(self.clone())));
+        f()
+    }
+}
+
+/// Unwinds with [`Cancelled`] if the token of the current thread is cancelled.
+pub fn checkpoint() {
+    if CURRENT.with(|current| current.borrow().as_ref().is_some_and(|token| token.is_cancelled())) {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+/// Cancels a token once `budget` has passed, unless it is dropped before.
+pub struct Watchdog {
+    _done: mpsc::Sender<()>,
+}
+
+impl Watchdog {
+    pub fn start(token: CancelToken, budget: Duration) -> Watchdog {
+        let (done, finished) = mpsc::channel::<()>();
+        thread::spawn(move || {
+            // Dropping the sender disconnects the channel, which ends the wait early
+            if let Err(RecvTimeoutError::Timeout) = finished.recv_timeout(budget) {
+                token.cancel();
+            }
+        });
+        Watchdog { _done: done }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn spin() {
+        loop {
+            checkpoint();
+        }
+    }
+
+    #[test]
+    fn test_checkpoint_without_token() {
+        checkpoint();
+        assert!(!CancelToken::current().is_cancelled());
+    }
+
+    #[test]
+    fn test_watchdog_cancels() {
+        let token = CancelToken::new();
+        let start = Instant::now();
+        let _watchdog = Watchdog::start(token.clone(), Duration::from_millis(50));
+
+        let result = panic::catch_unwind(|| token.scope(spin));
+        assert!(result.unwrap_err().is::<Cancelled>());
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        // The token is uninstalled again
+        checkpoint();
+    }
+
+    #[test]
+    fn test_dropped_watchdog_does_not_cancel() {
+        let token = CancelToken::new();
+        drop(Watchdog::start(token.clone(), Duration::from_millis(10)));
+        thread::sleep(Duration::from_millis(50));
+        assert!(!token.is_cancelled());
+    }
+
+    #[test]
+    fn test_scope_passes_token_to_other_threads() {
+        let token = CancelToken::new();
+        token.cancel();
+        let cancelled = token.scope(|| {
+            let current = CancelToken::current();
+            thread::spawn(move || panic::catch_unwind(|| current.scope(checkpoint)).is_err()).join().unwrap()
+        });
+        assert!(cancelled);
+    }
+}
@@ -0,0 +1,165 @@
+//! Client for the Advent of Code website, authenticated with the session cookie of a logged-in browser.
+//!
+//! Requests are throttled: each one waits until `min_interval` has passed since the previous one.
+//! With a throttle file the time of the last request is shared by all runs, not just this one.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::registry::Part;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2023;
+/// Where the runner keeps the time of its last request.
+pub const THROTTLE_FILE: &str = ".aoc_last_request";
+/// Identifies the tool to the site's operators, as they ask automated tools to do.
+const USER_AGENT: &str = concat!("aoc runner of ", env!("CARGO_PKG_NAME"), " ", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// The server answered with an error status, e.g. 400 for an expired session or 404 for a locked day
+    Http { status: u16, body: String },
+    /// The server could not be reached
+    Transport(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Http { status, body } => write!(f, "Server responded with {}: {}", status, body.trim()),
+            ClientError::Transport(e) => write!(f, "Cannot reach server: {}", e),
+            ClientError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => ClientError::Http {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            },
+            ureq::Error::Transport(t) => ClientError::Transport(t.to_string()),
+        }
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    throttle_file: Option<PathBuf>,
+    last_request: Option<SystemTime>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            min_interval: Duration::from_secs(5),
+            throttle_file: None,
+            last_request: None,
+        }
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Client {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Keeps the time of the last request in `path`, to throttle across runs.
+    pub fn with_throttle_file(mut self, path: &Path) -> Client {
+        self.throttle_file = Some(path.to_path_buf());
+        self
+    }
+
+    fn throttle(&mut self) -> io::Result<()> {
+        let from_file = self.throttle_file.as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+
+        if let Some(last) = self.last_request.max(from_file) {
+            let since = SystemTime::now().duration_since(last).unwrap_or_default();
+            if since < self.min_interval {
+                thread::sleep(self.min_interval - since);
+            }
+        }
+
+        let now = SystemTime::now();
+        self.last_request = Some(now);
+        if let Some(path) = &self.throttle_file {
+            fs::write(path, now.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis().to_string())?;
+        }
+        Ok(())
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&mut self, path: &str) -> Result<String, ClientError> {
+        self.throttle()?;
+        let response = self.agent.get(&self.url(path)).set("Cookie", &self.cookie()).call()?;
+        Ok(response.into_string()?)
+    }
+
+    fn post_form(&mut self, path: &str, fields: &[(&str, &str)]) -> Result<String, ClientError> {
+        self.throttle()?;
+        let response = self.agent.post(&self.url(path)).set("Cookie", &self.cookie()).send_form(fields)?;
+        Ok(response.into_string()?)
+    }
+
+    /// Personal puzzle input of a day.
+    pub fn input(&mut self, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("{}/input", day))
+    }
+
+    /// Submits an answer, returning the HTML page with the verdict.
+    pub fn answer(&mut self, day: u8, part: Part, answer: &str) -> Result<String, ClientError> {
+        self.post_form(&format!("{}/answer", day), &[("level", &part.to_string()), ("answer", answer)])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// The file was there already, nothing was downloaded
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the input of a day to `path`, unless the file is there already.
+/// Empty files, like the placeholders created for a new day, do not count.
+pub fn fetch_input(client: &mut Client, day: u8, path: &Path) -> Result<Fetched, ClientError> {
+    if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client.input(day)?;
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    fs::write(path, input)?;
+    Ok(Fetched::Downloaded)
+}
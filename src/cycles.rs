@@ -0,0 +1,173 @@
+//! Cycle detection in sequences of states, where each state follows from the previous one by a step function.
+//!
+//! Such a sequence runs through a tail of states that never repeat, then loops forever over a cycle.
+//! Knowing both lengths, the state after any number of steps can be found without taking them all.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps before the first state that repeats
+    pub tail: usize,
+    /// Steps it takes to come back to a state within the cycle
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Smallest number of steps that leads to the same state as `n` steps do.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        match n < self.tail {
+            true => n,
+            false => self.tail + (n - self.tail) % self.length,
+        }
+    }
+
+    /// State after `n` steps from `start`, taking fewer than `tail + length` steps.
+    pub fn state_after<S: Clone>(&self, start: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        let mut state = start.clone();
+        for _ in 0..self.equivalent_step(n) {
+            state = step(&state);
+        }
+        state
+    }
+}
+
+/// Brent's algorithm: keeps two states at a time and takes about `tail + length` steps a few times over.
+pub fn find_cycle_brent<S: Clone + Eq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the length by letting the hare run in power-of-two stretches, the tortoise waiting at each start
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Find the tail: with the hare one cycle ahead, they meet at the first state of the cycle
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut tail = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+
+    Cycle { tail, length }
+}
+
+/// Floyd's algorithm: keeps two states at a time, the hare stepping twice as fast as the tortoise.
+pub fn find_cycle_floyd<S: Clone + Eq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Meet somewhere in the cycle, at a multiple of its length
+    let mut tortoise = step(start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let halfway = step(&hare);
+        hare = step(&halfway);
+    }
+
+    // Find the tail: from the start and from the meeting point, both reach the first state of the cycle together
+    let mut tail = 0;
+    let mut tortoise = start.clone();
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+
+    // Find the length
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { tail, length }
+}
+
+/// Remembers every state, so it takes exactly `tail + length` steps.
+/// Also returns all those states, so that `states[cycle.equivalent_step(n)]` is the state after `n` steps.
+pub fn find_cycle_hashed<S: Clone + Eq + Hash>(start: &S, mut step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states = vec![];
+    let mut state = start.clone();
+    loop {
+        if let Some(&tail) = seen.get(&state) {
+            return (Cycle { tail, length: states.len() - tail }, states);
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// State after `n` steps from `start`, however large `n` is.
+pub fn state_after<S: Clone + Eq + Hash>(start: &S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    let (cycle, mut states) = find_cycle_hashed(start, step);
+    states.swap_remove(cycle.equivalent_step(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3 -> ...
+    fn step(&n: &u32) -> u32 {
+        match n {
+            6 => 3,
+            n => n + 1,
+        }
+    }
+
+    #[test]
+    fn test_algorithms_agree() {
+        let expected = Cycle { tail: 3, length: 4 };
+        assert_eq!(find_cycle_brent(&0, step), expected);
+        assert_eq!(find_cycle_floyd(&0, step), expected);
+        assert_eq!(find_cycle_hashed(&0, step), (expected, vec![0, 1, 2, 3, 4, 5, 6]));
+    }
+
+    #[test]
+    fn test_no_tail() {
+        let expected = Cycle { tail: 0, length: 7 };
+        let step = |&n: &u32| (n + 1) % 7;
+        assert_eq!(find_cycle_brent(&0, step), expected);
+        assert_eq!(find_cycle_floyd(&0, step), expected);
+        assert_eq!(find_cycle_hashed(&0, step).0, expected);
+        assert_eq!(find_cycle_brent(&5, |&n: &u32| n), Cycle { tail: 0, length: 1 });
+        assert_eq!(find_cycle_floyd(&5, |&n: &u32| n), Cycle { tail: 0, length: 1 });
+    }
+
+    #[test]
+    fn test_state_after() {
+        let cycle = find_cycle_brent(&0, step);
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(8), 4);
+        assert_eq!(cycle.state_after(&0, step, 8), 4);
+        assert_eq!(state_after(&0, step, 1_000_000_000), 3 + (1_000_000_000 - 3) % 4);
+    }
+
+    #[test]
+    fn test_state_after_billion_steps() {
+        // Quadratic map x -> x^2 + 1 (mod 1000), which falls into a cycle after a few steps
+        let step = |&x: &u64| (x * x + 1) % 1000;
+        let slow = (0..1000 + 17).fold(2, |x, _| step(&x));
+        let cycle = find_cycle_hashed(&2, step).0;
+        assert!(cycle.tail + cycle.length <= 1000);
+        assert_eq!(state_after(&2, step, 1000 + 17), slow);
+        assert_eq!(state_after(&2, step, 1_000_000_000), cycle.state_after(&2, step, 1_000_000_000));
+    }
+}
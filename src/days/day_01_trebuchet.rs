@@ -0,0 +1,440 @@
+//! Solution to an Advent of Code problem, day 01, 2023
+//! https://adventofcode.com/2023/day/01
+//!
+//! Digits are found by an Aho-Corasick automaton built once from a vocabulary, which reports overlapping
+//! matches like both digits of "eightwo" in a single pass over each line. Part 2 reads English words by
+//! default; the `words` parameter picks German or French instead, or a word list of one's own.
+//!
+//! `aoc explain 1` lists the digits found in each line, to see where a wrong answer comes from;
+//! with `--disagree` only in the lines where the parts disagree.
+//!
+//! Inputs too big to hold in memory are solved from a reader with [`calibrate_reader`], a line at a time,
+//! or [`calibrate_parallel`], which solves chunks of lines on rayon's threads (see `src/bin/trebuchet.rs`).
+
+use std::fmt;
+use std::fmt::Write;
+use std::fs;
+use std::io::{self, BufRead, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use aho_corasick::AhoCorasick;
+use rayon::prelude::*;
+
+use crate::params::{Param, ParamInfo};
+use crate::registry::Part;
+use crate::{ParseError, Solution};
+
+const WORDS: Param<WordList> = Param::new(
+    "words", WordList::English, "Spelled digits of part 2: english, german, french, or a .json or .toml file");
+const ZERO: Param<bool> = Param::new("zero", false, "Whether part 2 also reads the word for zero");
+
+const ENGLISH: [&str; 10] = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+const GERMAN: [&str; 10] = ["null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"];
+const FRENCH: [&str; 10] = ["zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf"];
+
+/// Where the words of part 2 come from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WordList {
+    English,
+    German,
+    French,
+    /// A `.json` or `.toml` file mapping words to digits, read when the parameter is, so that
+    /// a missing or invalid file is reported before anything is solved.
+    File(PathBuf, Vocabulary),
+}
+
+impl FromStr for WordList {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "english" => Ok(WordList::English),
+            "german" => Ok(WordList::German),
+            "french" => Ok(WordList::French),
+            _ => {
+                let path = PathBuf::from(s);
+                let vocabulary = Vocabulary::read(&path)?;
+                Ok(WordList::File(path, vocabulary))
+            }
+        }
+    }
+}
+
+impl fmt::Display for WordList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordList::English => write!(f, "english"),
+            WordList::German => write!(f, "german"),
+            WordList::French => write!(f, "french"),
+            WordList::File(path, _) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Words standing for digits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vocabulary {
+    words: Vec<(String, u64)>,
+}
+
+impl Vocabulary {
+    pub fn numerals() -> Vocabulary {
+        Vocabulary { words: (0..10).map(|d| (d.to_string(), d)).collect() }
+    }
+
+    /// The words of a language, from one to nine, and also zero if `zero` is set.
+    fn language(words: &[&str; 10], zero: bool) -> Vocabulary {
+        let first = match zero {
+            true => 0,
+            false => 1,
+        };
+        Vocabulary { words: (first..10).map(|d| (words[d].to_string(), d as u64)).collect() }
+    }
+
+    fn from_entries(entries: impl IntoIterator<Item = (String, Option<u64>)>) -> Result<Vocabulary, String> {
+        let mut words = Vec::new();
+        for (word, value) in entries {
+            match value {
+                _ if word.is_empty() => return Err("Empty word in word list".to_string()),
+                Some(value) if value < 10 => words.push((word, value)),
+                _ => return Err(format!("Not a digit in word list: {}", word)),
+            }
+        }
+        Ok(Vocabulary { words })
+    }
+
+    /// A word list as a JSON object, like `{"uno": 1, "dos": 2}`.
+    pub fn from_json(s: &str) -> Result<Vocabulary, String> {
+        let value: serde_json::Value = serde_json::from_str(s).map_err(|e| format!("Invalid word list: {}", e))?;
+        let object = value.as_object().ok_or("Word list is not an object")?;
+        Vocabulary::from_entries(object.iter().map(|(word, value)| (word.clone(), value.as_u64())))
+    }
+
+    /// A word list as a TOML table, like `uno = 1`.
+    pub fn from_toml(s: &str) -> Result<Vocabulary, String> {
+        let table: toml::Table = s.parse().map_err(|e| format!("Invalid word list: {}", e))?;
+        Vocabulary::from_entries(table.into_iter()
+            .map(|(word, value)| (word, value.as_integer().and_then(|v| u64::try_from(v).ok()))))
+    }
+
+    /// A word list from a `.json` or `.toml` file.
+    pub fn read(path: &Path) -> Result<Vocabulary, String> {
+        let extension = path.extension().and_then(|e| e.to_str());
+        if !matches!(extension, Some("json" | "toml")) {
+            return Err(format!("Unknown word list: {}", path.display()));
+        }
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read word list {}: {}", path.display(), e))?;
+        match extension {
+            Some("toml") => Vocabulary::from_toml(&contents),
+            _ => Vocabulary::from_json(&contents),
+        }
+    }
+
+    /// The words of a language, or those of a file. `zero` only applies to languages;
+    /// a file lists the words it wants.
+    pub fn load(list: &WordList, zero: bool) -> Vocabulary {
+        match list {
+            WordList::English => Vocabulary::language(&ENGLISH, zero),
+            WordList::German => Vocabulary::language(&GERMAN, zero),
+            WordList::French => Vocabulary::language(&FRENCH, zero),
+            WordList::File(_, vocabulary) => vocabulary.clone(),
+        }
+    }
+}
+
+/// A digit found in a line, at bytes `start..end`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DigitMatch {
+    start: usize,
+    end: usize,
+    value: u64,
+    /// Whether it is a word rather than a numeral.
+    spelled: bool,
+}
+
+impl DigitMatch {
+    fn overlaps(&self, other: &DigitMatch) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+/// Finds the digits of a line, numerals or words, in a single pass.
+pub struct DigitScanner {
+    automaton: AhoCorasick,
+    /// Value of each pattern, and whether it is spelled.
+    values: Vec<(u64, bool)>,
+}
+
+impl DigitScanner {
+    /// The scanner of a part: numerals, and in part 2 the words picked by the parameters.
+    pub fn for_part(part: Part) -> DigitScanner {
+        match part {
+            Part::One => DigitScanner::new(&[Vocabulary::numerals()]),
+            Part::Two => DigitScanner::new(&[Vocabulary::numerals(), Vocabulary::load(&WORDS.get(), ZERO.get())]),
+        }
+    }
+
+    pub fn new(vocabularies: &[Vocabulary]) -> DigitScanner {
+        let (words, values): (Vec<&str>, Vec<(u64, bool)>) = vocabularies.iter()
+            .flat_map(|v| v.words.iter())
+            .map(|(word, value)| (word.as_str(), (*value, !word.bytes().all(|b| b.is_ascii_digit()))))
+            .unzip();
+        DigitScanner {
+            automaton: AhoCorasick::new(words).expect("Word lists are small"),
+            values,
+        }
+    }
+}
+
+/// All digits of the line, also overlapping ones, by where they start.
+fn find_all_digits(input: &str, scanner: &DigitScanner) -> Vec<DigitMatch> {
+    let mut matches: Vec<DigitMatch> = scanner.automaton.find_overlapping_iter(input)
+        .map(|m| {
+            let (value, spelled) = scanner.values[m.pattern().as_usize()];
+            DigitMatch { start: m.start(), end: m.end(), value, spelled }
+        })
+        .collect();
+    // Overlapping matches are reported by where they end, which differs for words of different lengths
+    matches.sort_by_key(|m| m.start);
+    matches
+}
+
+fn sum_up(digits: &[DigitMatch]) -> u64 {
+    if !digits.is_empty() {
+        digits[0].value * 10 + digits[digits.len() - 1].value
+    } else {
+        0
+    }
+}
+
+/// What `sum_up` makes of the digits of the line, without collecting them.
+fn calibration_value(line: &str, scanner: &DigitScanner) -> u64 {
+    let mut first: Option<(usize, u64)> = None;
+    let mut last: Option<(usize, u64)> = None;
+    // Matches come by where they end; on a tie of starts, the first digit is the shortest one and the last the longest
+    for m in scanner.automaton.find_overlapping_iter(line) {
+        let value = scanner.values[m.pattern().as_usize()].0;
+        if first.is_none_or(|(start, _)| m.start() < start) {
+            first = Some((m.start(), value));
+        }
+        if last.is_none_or(|(start, _)| m.start() >= start) {
+            last = Some((m.start(), value));
+        }
+    }
+    match (first, last) {
+        (Some((_, first)), Some((_, last))) => first * 10 + last,
+        _ => 0,
+    }
+}
+
+fn calibrate(lines: &[String], scanner: &DigitScanner) -> u64 {
+    lines.iter().map(|line| calibration_value(line, scanner)).sum()
+}
+
+/// Sums of the calibration values of the lines of `reader` for each scanner, reading a line at a time.
+pub fn calibrate_reader(mut reader: impl BufRead, scanners: &[DigitScanner]) -> io::Result<Vec<u64>> {
+    let mut sums = vec![0; scanners.len()];
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 0 {
+        let trimmed = line.trim_end_matches(['\n', '\r']);
+        for (sum, scanner) in sums.iter_mut().zip(scanners) {
+            *sum += calibration_value(trimmed, scanner);
+        }
+        line.clear();
+    }
+    Ok(sums)
+}
+
+/// Like [`calibrate_reader`], with chunks of about `chunk_size` bytes solved in parallel, as many at a time
+/// as rayon has threads. The chunks end at line ends, and their sums are added in the order of the input.
+pub fn calibrate_parallel(mut reader: impl BufRead, scanners: &[DigitScanner], chunk_size: usize)
+                          -> io::Result<Vec<u64>> {
+    let mut sums = vec![0; scanners.len()];
+    loop {
+        let mut chunks = Vec::new();
+        while chunks.len() < rayon::current_num_threads() {
+            match read_chunk(&mut reader, chunk_size)? {
+                chunk if chunk.is_empty() => break,
+                chunk => chunks.push(chunk),
+            }
+        }
+        if chunks.is_empty() {
+            return Ok(sums);
+        }
+
+        let chunk_sums: Vec<Vec<u64>> = chunks.par_iter()
+            .map(|chunk| scanners.iter().map(|scanner| calibrate_chunk(chunk, scanner)).collect())
+            .collect();
+        for chunk_sum in chunk_sums {
+            for (sum, s) in sums.iter_mut().zip(chunk_sum) {
+                *sum += s;
+            }
+        }
+    }
+}
+
+fn calibrate_chunk(chunk: &str, scanner: &DigitScanner) -> u64 {
+    chunk.lines().map(|line| calibration_value(line, scanner)).sum()
+}
+
+/// Reads about `size` bytes, and then on to the end of the line. Empty at the end of the input.
+fn read_chunk(reader: &mut impl BufRead, size: usize) -> io::Result<String> {
+    let mut bytes = Vec::with_capacity(size);
+    reader.by_ref().take(size as u64).read_to_end(&mut bytes)?;
+    if bytes.last().is_some_and(|&b| b != b'\n') {
+        reader.read_until(b'\n', &mut bytes)?;
+    }
+    String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// The digits part 2 finds in line `number`, with their byte offsets, and the line's values in both parts.
+/// Digits sharing letters with another one are marked, as they are easy to miss. With `disagreeing_only`,
+/// None for a line whose value is the same in both parts.
+fn explain_line(number: usize, line: &str, numerals: &DigitScanner, words: &DigitScanner, disagreeing_only: bool)
+                -> Option<String> {
+    let (one, two) = (sum_up(&find_all_digits(line, numerals)), find_all_digits(line, words));
+    if disagreeing_only && one == sum_up(&two) {
+        return None;
+    }
+
+    let mut out = String::new();
+    writeln!(out, "Line {}: {} = {} (part 1: {})", number, line, sum_up(&two), one).unwrap();
+    for m in &two {
+        let source = match m.spelled {
+            true => "word",
+            false => "numeral",
+        };
+        let overlapping: Vec<&str> = two.iter()
+            .filter(|other| *other != m && other.overlaps(m))
+            .map(|other| &line[other.start..other.end])
+            .collect();
+        let overlaps = match overlapping.is_empty() {
+            true => String::new(),
+            false => format!("overlaps {}", overlapping.join(", ")),
+        };
+        let row = format!("  {:>4}  {:<8}{:<9}{}", m.start, &line[m.start..m.end], source, overlaps);
+        writeln!(out, "{}", row.trim_end()).unwrap();
+    }
+    Some(out)
+}
+
+/// Explains the lines of `reader` to `out`, a line at a time, with the words picked by the parameters.
+/// With `disagreeing_only`, only the lines whose value differs between the parts.
+pub fn explain_reader(mut reader: impl BufRead, out: &mut impl io::Write, disagreeing_only: bool) -> io::Result<()> {
+    let (numerals, words) = (DigitScanner::for_part(Part::One), DigitScanner::for_part(Part::Two));
+    let mut line = String::new();
+    let mut number = 0;
+    while reader.read_line(&mut line)? > 0 {
+        number += 1;
+        let line_text = line.trim_end_matches(['\n', '\r']);
+        if let Some(explanation) = explain_line(number, line_text, &numerals, &words, disagreeing_only) {
+            out.write_all(explanation.as_bytes())?;
+        }
+        line.clear();
+    }
+    Ok(())
+}
+
+pub struct Trebuchet;
+
+impl Solution for Trebuchet {
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const PARAMS: &'static [&'static dyn ParamInfo] = &[&WORDS, &ZERO];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(|l| l.to_string()).collect())
+    }
+
+    fn part_one(lines: &Self::Input) -> u64 {
+        calibrate(lines, &DigitScanner::for_part(Part::One))
+    }
+
+    fn part_two(lines: &Self::Input) -> u64 {
+        calibrate(lines, &DigitScanner::for_part(Part::Two))
+    }
+
+    fn explain(lines: &Self::Input, disagreeing_only: bool) -> Option<String> {
+        let (numerals, words) = (DigitScanner::for_part(Part::One), DigitScanner::for_part(Part::Two));
+        Some(lines.iter().enumerate()
+            .filter_map(|(i, line)| explain_line(i + 1, line, &numerals, &words, disagreeing_only))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn english() -> DigitScanner {
+        DigitScanner::new(&[Vocabulary::numerals(), Vocabulary::load(&WordList::English, false)])
+    }
+
+    fn digits(input: &str, scanner: &DigitScanner) -> Vec<u64> {
+        find_all_digits(input, scanner).iter().map(|m| m.value).collect()
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        assert_eq!(digits("eightwothree", &english()), [8, 2, 3]);
+        assert_eq!(digits("zoneight234", &english()), [1, 8, 2, 3, 4]);
+        assert_eq!(digits("sevenine", &DigitScanner::new(&[Vocabulary::numerals()])), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn test_languages() {
+        let german = DigitScanner::new(&[Vocabulary::load(&WordList::German, true)]);
+        assert_eq!(digits("nullfünfzweins", &german), [0, 5, 2, 1]);
+        let french = DigitScanner::new(&[Vocabulary::load(&WordList::French, false)]);
+        assert_eq!(digits("xhuitroisept", &french), [8, 3, 7]);
+    }
+
+    #[test]
+    fn test_word_lists() {
+        let json = Vocabulary::from_json(r#"{"uno": 1, "dos": 2}"#).unwrap();
+        let toml = Vocabulary::from_toml("uno = 1\ndos = 2").unwrap();
+        assert_eq!(digits("dosuno", &DigitScanner::new(&[json])), [2, 1]);
+        assert_eq!(digits("dosuno", &DigitScanner::new(&[toml])), [2, 1]);
+        assert_eq!(Vocabulary::from_json(r#"{"diez": 10}"#), Err("Not a digit in word list: diez".to_string()));
+        assert_eq!("spanish".parse::<WordList>(), Err("Unknown word list: spanish".to_string()));
+        // Checked with the other parameters, before anything is solved
+        assert!(WORDS.check("missing.json").unwrap_err().contains("Cannot read word list missing.json"));
+    }
+
+    #[test]
+    fn test_calibrate_reader() {
+        let input = "two1nine\neightwothree\r\nabcone2threexyz\nxtwone3four\n\
+                     4nineeightseven2\nzoneight234\n7pqrstsixteen";
+        let scanners = [DigitScanner::for_part(Part::One), english()];
+        assert_eq!(calibrate_reader(input.as_bytes(), &scanners).unwrap(), [209, 281]);
+        for chunk_size in [1, 5, 16, 1 << 20] {
+            assert_eq!(calibrate_parallel(input.as_bytes(), &scanners, chunk_size).unwrap(), [209, 281]);
+        }
+    }
+
+    #[test]
+    fn test_explain_line() {
+        let numerals = DigitScanner::new(&[Vocabulary::numerals()]);
+        let explain = |line, disagreeing_only| explain_line(1, line, &numerals, &english(), disagreeing_only);
+        assert_eq!(explain("eightwo", true).unwrap().lines().collect::<Vec<_>>(), [
+            "Line 1: eightwo = 82 (part 1: 0)",
+            "     0  eight   word     overlaps two",
+            "     4  two     word     overlaps eight",
+        ]);
+        assert_eq!(explain("a1b2", false).unwrap().lines().collect::<Vec<_>>(),
+                   ["Line 1: a1b2 = 12 (part 1: 12)", "     1  1       numeral", "     3  2       numeral"]);
+        assert_eq!(explain("a1b2", true), None);
+    }
+
+    #[test]
+    fn test_explain_reader() {
+        let mut out = vec![];
+        explain_reader("1two\r\n3\n".as_bytes(), &mut out, false).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().lines().filter(|l| l.starts_with("Line")).collect::<Vec<_>>(),
+                   ["Line 1: 1two = 12 (part 1: 11)", "Line 2: 3 = 33 (part 1: 33)"]);
+    }
+}
@@ -0,0 +1,402 @@
+//! Solution to an Advent of Code problem, day 02, 2023
+//! https://adventofcode.com/2023/day/02
+//!
+//! Cubes can have any colour, not only red, green and blue: a cube set counts cubes by the colour words
+//! of the input, and the bag of part 1 is a `bag` parameter written like a cube set of the input.
+//!
+//! `aoc explain 2` prints a table of the games with their minimal bags and the colours that make them impossible
+//! with the bag, the smallest bag for all games, and the bags that make the most games possible for their number
+//! of cubes.
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use itertools::Itertools;
+use log::trace;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, space0};
+use nom::error::ErrorKind;
+use nom::IResult;
+use nom::multi::separated_list1;
+use nom::sequence::tuple;
+
+use crate::params::{Param, ParamInfo};
+use crate::{parse_all, ParseError, Solution};
+
+const BAG: Param<BagSpec> = Param::new("bag", BagSpec(Cow::Borrowed("12 red, 13 green, 14 blue")),
+                                        "Cubes in the bag of part 1, e.g. \"7 red, 2 purple\"");
+
+/// Most bags `pareto_frontier` tries, as their number grows exponentially with the colours.
+const MAX_BAGS: usize = 1 << 20;
+
+
+/// Counts of cubes by colour. Colours that are not listed count zero cubes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CubeSet(BTreeMap<String, u64>);
+
+impl CubeSet {
+    fn new() -> CubeSet {
+        CubeSet::default()
+    }
+
+    pub fn get(&self, colour: &str) -> u64 {
+        self.0.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(|c| c.as_str())
+    }
+
+    /// Whether every count is within that of the same colour in `bag`.
+    pub fn fits_in(&self, bag: &CubeSet) -> bool {
+        self.0.iter().all(|(colour, &count)| count <= bag.get(colour))
+    }
+
+    pub fn total(&self) -> u64 {
+        self.0.values().sum()
+    }
+
+    /// Product of the counts of the colours of `palette`, zero for those missing.
+    fn power(&self, palette: &BTreeSet<&str>) -> u64 {
+        palette.iter().map(|colour| self.get(colour)).product()
+    }
+}
+
+impl<const N: usize> From<[(&str, u64); N]> for CubeSet {
+    fn from(counts: [(&str, u64); N]) -> Self {
+        CubeSet(counts.into_iter().map(|(colour, count)| (colour.to_string(), count)).collect())
+    }
+}
+
+impl fmt::Display for CubeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts: Vec<String> = self.0.iter().map(|(colour, count)| format!("{} {}", count, colour)).collect();
+        write!(f, "{}", counts.join(", "))
+    }
+}
+
+fn max(a: &CubeSet, b: &CubeSet) -> CubeSet {
+    let mut r = a.clone();
+    for (colour, &count) in &b.0 {
+        let entry = r.0.entry(colour.clone()).or_insert(0);
+        *entry = std::cmp::max(*entry, count);
+    }
+    r
+}
+
+/// A bag written like a cube set of the input, e.g. `12 red, 13 green, 14 blue`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BagSpec(Cow<'static, str>);
+
+impl BagSpec {
+    fn cube_set(&self) -> CubeSet {
+        parse_all(&self.0, &self.0, cube_set).expect("Checked when parsed")
+    }
+}
+
+impl FromStr for BagSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(s, s, cube_set).map_err(|e| e.to_string())?;
+        Ok(BagSpec(Cow::Owned(s.to_string())))
+    }
+}
+
+impl fmt::Display for BagSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    id: u64,
+    cube_sets: Vec<CubeSet>,
+}
+
+impl Game {
+    fn is_possible(&self, bag: &CubeSet) -> bool {
+        self.cube_sets.iter().all(|cube_set| cube_set.fits_in(bag))
+    }
+
+    fn min_cube_set(&self) -> CubeSet {
+        self.cube_sets.iter().fold(CubeSet::new(), |acc, cube_set| max(&acc, cube_set))
+    }
+}
+
+/// At least one count, and each colour once.
+fn cube_set(input: &str) -> IResult<&str, CubeSet> {
+    let count_and_color = tuple((nom::character::complete::u64, space0, alpha1));
+    let (input, counts_and_colors) = separated_list1(tag(", "), count_and_color)(input)?;
+
+    let mut r = CubeSet::new();
+    for (count, _, color) in counts_and_colors {
+        if r.0.insert(color.to_string(), count).is_some() {
+            // A failure, so that the draws before are not taken for the whole game
+            return Err(nom::Err::Failure(nom::error::Error::new(color, ErrorKind::Verify)));
+        }
+    }
+    Ok((input, r))
+}
+
+fn game(input: &str) -> IResult<&str, Game> {
+    let (input, _) = tag("Game ")(input)?;
+    let (input, id) = nom::character::complete::u64(input)?;
+    let (input, _) = tag(": ")(input)?;
+    let (input, cube_sets) = separated_list1(tag("; "), cube_set)(input)?;
+
+    Ok((input, Game {
+        id,
+        cube_sets,
+    }))
+}
+
+/// Ids of the games that are possible with `bag`.
+pub fn possible_games(games: &[Game], bag: &CubeSet) -> Vec<u64> {
+    games.iter().filter(|g| g.is_possible(bag)).map(|g| g.id).collect()
+}
+
+/// The smallest bag all games are possible with.
+pub fn minimal_bag(games: &[Game]) -> CubeSet {
+    games.iter().fold(CubeSet::new(), |acc, g| max(&acc, &g.min_cube_set()))
+}
+
+/// The colours whose limit in `bag` the game exceeds, each enough on its own to make it impossible.
+fn failing_colours(game: &Game, bag: &CubeSet) -> Vec<String> {
+    let min = game.min_cube_set();
+    min.colours().filter(|colour| min.get(colour) > bag.get(colour)).map(|colour| colour.to_string()).collect()
+}
+
+/// The bags no other bag beats, by number of cubes, with how many games they make possible. A bag is beaten
+/// by one with no more cubes that makes no fewer games possible, and is better in one of the two; bags that tie
+/// are all kept.
+///
+/// Only bags of counts that some game needs are tried, as any other bag is beaten by the one of such counts
+/// just below it: as many as the product of the distinct counts of each colour. That is small for puzzle
+/// inputs but grows exponentially with the colours, so inputs needing more than `MAX_BAGS` are rejected.
+pub fn pareto_frontier(games: &[Game]) -> Result<Vec<(CubeSet, usize)>, String> {
+    let minimal = minimal_bag(games);
+    let palette: Vec<&str> = minimal.colours().collect();
+    // What each game needs of the colours of the palette, in its order
+    let needs: Vec<Vec<u64>> = games.iter()
+        .map(|g| {
+            let min = g.min_cube_set();
+            palette.iter().map(|colour| min.get(colour)).collect()
+        })
+        .collect();
+    let thresholds: Vec<Vec<u64>> = (0..palette.len())
+        .map(|i| std::iter::once(0).chain(needs.iter().map(|need| need[i])).unique().sorted().collect())
+        .collect();
+    let bags = thresholds.iter().try_fold(1usize, |product, counts| product.checked_mul(counts.len()));
+    if bags.is_none_or(|bags| bags > MAX_BAGS) {
+        return Err(format!("Too many bags to try for the Pareto frontier: more than {}", MAX_BAGS));
+    }
+
+    // The most games possible with each number of cubes, and the bags that make them possible
+    let mut best_by_total: BTreeMap<u128, (usize, Vec<Vec<u64>>)> = BTreeMap::new();
+    for counts in thresholds.into_iter().multi_cartesian_product() {
+        let possible = needs.iter().filter(|need| need.iter().zip(&counts).all(|(need, count)| need <= count)).count();
+        let (most, bags) = best_by_total.entry(counts.iter().map(|&count| count as u128).sum()).or_default();
+        match possible.cmp(most) {
+            Ordering::Greater => (*most, *bags) = (possible, vec![counts]),
+            Ordering::Equal => bags.push(counts),
+            Ordering::Less => {}
+        }
+    }
+
+    // Bags with the most games for their number of cubes are beaten only by ones with fewer cubes
+    let mut frontier: Vec<(CubeSet, usize)> = Vec::new();
+    let mut best_with_fewer: Option<usize> = None;
+    for (most, bags) in best_by_total.into_values() {
+        if best_with_fewer.is_none_or(|best| most > best) {
+            for counts in bags {
+                let bag = CubeSet(palette.iter().zip(counts)
+                    .filter(|&(_, count)| count > 0)
+                    .map(|(colour, count)| (colour.to_string(), count))
+                    .collect());
+                frontier.push((bag, most));
+            }
+            best_with_fewer = Some(most);
+        }
+    }
+    Ok(frontier)
+}
+
+/// A table of the games with their minimal bags, whether they are possible with `bag` and which colours
+/// make them impossible, followed by the smallest bag for all games and the Pareto frontier of bags.
+fn analyse(games: &[Game], bag: &CubeSet) -> String {
+    let minimal = minimal_bag(games);
+    let palette: Vec<&str> = minimal.colours().collect();
+
+    let mut out = String::new();
+    write!(out, "{:>5}", "Game").unwrap();
+    for colour in &palette {
+        write!(out, "  {:>6}", colour).unwrap();
+    }
+    writeln!(out, "  {:>6}  {:<8}  Fails on", "Cubes", "Possible").unwrap();
+
+    for game in games {
+        let min = game.min_cube_set();
+        write!(out, "{:>5}", game.id).unwrap();
+        for colour in &palette {
+            write!(out, "  {:>6}", min.get(colour)).unwrap();
+        }
+        let failing: Vec<String> = failing_colours(game, bag).iter()
+            .map(|colour| format!("{} ({} > {})", colour, min.get(colour), bag.get(colour)))
+            .collect();
+        let possible = match failing.is_empty() {
+            true => "yes",
+            false => "no",
+        };
+        let row = format!("  {:>6}  {:<8}  {}", min.total(), possible, failing.join(", "));
+        writeln!(out, "{}", row.trim_end()).unwrap();
+    }
+
+    writeln!(out, "Smallest bag for all games: {} ({} cubes)", minimal, minimal.total()).unwrap();
+    match pareto_frontier(games) {
+        Ok(frontier) => {
+            writeln!(out, "Most games possible for the number of cubes:").unwrap();
+            for (bag, possible) in frontier {
+                let row = format!("  {:>6} cubes  {:>5} games  {}", bag.total(), possible, bag);
+                writeln!(out, "{}", row.trim_end()).unwrap();
+            }
+        }
+        Err(e) => writeln!(out, "{}", e).unwrap(),
+    }
+    out
+}
+
+pub struct CubeConundrum;
+
+impl Solution for CubeConundrum {
+    type Input = Vec<Game>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const PARAMS: &'static [&'static dyn ParamInfo] = &[&BAG];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let games = input.lines().map(|line| parse_all(input, line, game)).collect::<Result<Vec<_>, _>>()?;
+
+        trace!("{:?}", games);
+        Ok(games)
+    }
+
+    fn part_one(games: &Self::Input) -> u64 {
+        possible_games(games, &BAG.get().cube_set()).iter().sum()
+    }
+
+    fn part_two(games: &Self::Input) -> u64 {
+        // Every colour of the input counts, so a game without cubes of one colour has no power
+        let minimal = minimal_bag(games);
+        let palette: BTreeSet<&str> = minimal.colours().collect();
+        games.iter().map(|g| g.min_cube_set().power(&palette)).sum()
+    }
+
+    fn explain(games: &Self::Input, _disagreeing_only: bool) -> Option<String> {
+        Some(analyse(games, &BAG.get().cube_set()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn test_cube_set() {
+        assert_eq!(cube_set("1 red, 2 green, 6 blue"),
+                   Ok(("", CubeSet::from([("red", 1), ("green", 2), ("blue", 6)]))));
+        assert_eq!(cube_set("4 purple"), Ok(("", CubeSet::from([("purple", 4)]))));
+    }
+
+    #[test]
+    fn test_game() {
+        assert_eq!(game("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"),
+                   Ok(("", Game {
+                       id: 5,
+                       cube_sets: vec![
+                           CubeSet::from([("red", 6), ("green", 3), ("blue", 1)]),
+                           CubeSet::from([("red", 1), ("green", 2), ("blue", 2)]),
+                       ],
+                   })));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_lines() {
+        let e = CubeConundrum::parse("Game 1: 3 blue\nGame 2: four purple\n").err().unwrap();
+        assert_eq!((e.line, e.column), (2, 9));
+    }
+
+    #[test]
+    fn test_parse_rejects_repeated_colours_and_empty_draws() {
+        let e = CubeConundrum::parse("Game 1: 3 blue; 1 red, 2 red\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.kind), (1, 26, ErrorKind::Verify));
+        let e = CubeConundrum::parse("Game 1: 3 blue; \n").err().unwrap();
+        assert_eq!((e.line, e.column), (1, 15));
+        assert!(CubeConundrum::parse("Game 1: \n").is_err());
+        assert!("".parse::<BagSpec>().is_err());
+    }
+
+    #[test]
+    fn test_any_palette() {
+        let games = CubeConundrum::parse("Game 1: 3 teal, 1 red\nGame 2: 2 teal; 5 ochre\nGame 3: 9 red").unwrap();
+        let bag = CubeSet::from([("teal", 3), ("red", 1), ("ochre", 5)]);
+        assert_eq!(possible_games(&games, &bag), [1, 2]);
+        assert_eq!(minimal_bag(&games), CubeSet::from([("ochre", 5), ("red", 9), ("teal", 3)]));
+        assert_eq!(minimal_bag(&games).to_string(), "5 ochre, 9 red, 3 teal");
+    }
+
+    fn example() -> Vec<Game> {
+        CubeConundrum::parse("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green").unwrap()
+    }
+
+    #[test]
+    fn test_failing_colours() {
+        let games = example();
+        let bag = CubeSet::from([("red", 12), ("green", 13), ("blue", 14)]);
+        assert_eq!(games.iter().map(|g| failing_colours(g, &bag)).collect_vec(),
+                   [vec![], vec![], vec!["red"], vec!["blue", "red"], vec![]] as [Vec<&str>; 5]);
+    }
+
+    #[test]
+    fn test_pareto_frontier() {
+        let frontier: Vec<(String, u64, usize)> = pareto_frontier(&example()).unwrap().into_iter()
+            .map(|(bag, possible)| (bag.to_string(), bag.total(), possible))
+            .collect();
+        assert_eq!(frontier, [
+            ("".to_string(), 0, 0),
+            ("4 blue, 3 green, 1 red".to_string(), 8, 1),
+            // Both bags of 13 cubes make two games possible
+            ("4 blue, 3 green, 6 red".to_string(), 13, 2),
+            ("6 blue, 3 green, 4 red".to_string(), 13, 2),
+            ("6 blue, 3 green, 6 red".to_string(), 15, 3),
+            ("15 blue, 3 green, 14 red".to_string(), 32, 4),
+            ("15 blue, 13 green, 20 red".to_string(), 48, 5),
+        ]);
+
+        let rainbow = CubeConundrum::parse("Game 1: 1 red, 1 orange, 1 yellow, 1 green, 1 blue, 1 violet").unwrap();
+        assert_eq!(pareto_frontier(&rainbow).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_pareto_frontier_of_many_counts() {
+        // Few colours, but 61 counts of each to try: about 14 million bags
+        let input = (1..=60u64)
+            .map(|id| format!("Game {}: {} red, {} green, {} blue, {} teal", id, id, 61 - id, id * 3, id * 7 % 61))
+            .join("\n");
+        let games = CubeConundrum::parse(&input).unwrap();
+        let error = "Too many bags to try for the Pareto frontier: more than 1048576".to_string();
+        assert_eq!(pareto_frontier(&games), Err(error));
+        assert_eq!(pareto_frontier(&games[..12]).unwrap().last().unwrap().1, 12);
+    }
+}
@@ -0,0 +1,106 @@
+//! Solution to an Advent of Code problem, day 03, 2023
+//! https://adventofcode.com/2023/day/03
+
+use std::ops::Range;
+use itertools::Itertools;
+use log::trace;
+
+use crate::grid::Grid;
+use crate::{parse_all, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Number {
+    y: usize,
+    x_range: Range<usize>,
+    value: u64,
+}
+
+impl Number {
+    fn cells(&self) -> impl Iterator<Item=(usize, usize)> + '_ {
+        self.x_range.clone().map(|x| (x, self.y))
+    }
+
+    fn is_part(&self, schematic: &Grid<u8>) -> bool {
+        self.cells()
+            .flat_map(|(x, y)| schematic.neighbours8(x, y))
+            .any(|(x, y)| {
+                let b = schematic[(x, y)];
+                !b.is_ascii_digit() && b != b'.'
+            })
+    }
+
+    fn is_adjacent(&self, schematic: &Grid<u8>, x: usize, y: usize) -> bool {
+        schematic.neighbours8(x, y).any(|(nx, ny)| ny == self.y && self.x_range.contains(&nx))
+    }
+}
+
+pub struct Schematic {
+    rows: Grid<u8>,
+    numbers: Vec<Number>,
+}
+
+impl Schematic {
+    fn parts(&self) -> Vec<&Number> {
+        self.numbers.iter().filter(|n| n.is_part(&self.rows)).collect_vec()
+    }
+}
+
+pub struct GearRatios;
+
+impl Solution for GearRatios {
+    type Input = Schematic;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rows = Grid::parse(input)?;
+        let w = rows.width();
+        let mut numbers: Vec<Number> = vec![];
+
+        for (y, line) in input.lines().enumerate() {
+            let row = rows.row(y);
+            let mut x = 0;
+            while x < w {
+                if row[x].is_ascii_digit() {
+                    let x_start = x;
+                    let mut x_end = x + 1;
+                    while x_end < w && row[x_end].is_ascii_digit() {
+                        x_end += 1;
+                    }
+
+                    let value = parse_all(input, &line[x_start..x_end], nom::character::complete::u64)?;
+                    x = x_end;
+                    numbers.push(Number {
+                        y,
+                        x_range: x_start..x_end,
+                        value,
+                    });
+                } else {
+                    x += 1;
+                }
+            }
+        }
+
+        Ok(Schematic { rows, numbers })
+    }
+
+    fn part_one(schematic: &Self::Input) -> u64 {
+        schematic.parts().iter().map(|p| p.value).sum()
+    }
+
+    fn part_two(schematic: &Self::Input) -> u64 {
+        let parts = schematic.parts();
+
+        let mut gear_ratios = 0;
+        for ((x, y), &cell) in schematic.rows.iter() {
+            if cell == b'*' {
+                let adjacent_parts = parts.iter().filter(|p| p.is_adjacent(&schematic.rows, x, y)).collect_vec();
+                trace!("Parts adjacent to * at ({}, {}): {:?}", x, y, adjacent_parts);
+                if adjacent_parts.len() == 2 {
+                    gear_ratios += adjacent_parts[0].value * adjacent_parts[1].value;
+                }
+            }
+        }
+        gear_ratios
+    }
+}
@@ -1,17 +1,17 @@
-/// Solution to an Advent of Code problem, day 04, 2023
-/// https://adventofcode.com/2023/day/04
+//! Solution to an Advent of Code problem, day 04, 2023
+//! https://adventofcode.com/2023/day/04
 
-use std::env;
-use std::fs;
-use itertools::Itertools;
+use log::trace;
 use nom::bytes::complete::tag;
 use nom::character::complete::space1;
 use nom::IResult;
 use nom::multi::separated_list1;
 use nom::sequence::tuple;
 
+use crate::{parse_all, ParseError, Solution};
+
 #[derive(Debug)]
-struct Card {
+pub struct Card {
     winning_numbers: Vec<u64>,
     my_numbers: Vec<u64>,
 }
@@ -31,7 +31,7 @@ impl Card {
         let mut matching_count: u32 = 0;
         for n in &self.my_numbers {
             if self.winning_numbers.contains(n) {
-                matching_count = matching_count + 1;
+                matching_count += 1;
             }
         }
         matching_count
@@ -55,23 +55,31 @@ fn parse_card(s: &str) -> IResult<&str, Card> {
     Ok((rest, Card { winning_numbers, my_numbers }))
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
-    let contents = fs::read_to_string(filename).expect("Cannot read file");
-    let lines = contents.lines();
-    let cards = lines.map(|l| parse_card(l).unwrap().1).collect_vec();
-    //println!("{:?}", cards);
-    let scores_part_1: u64 = cards.iter().map(|c| c.score()).sum();
-    println!("Total points [part 1]: {}", scores_part_1);
+pub struct Scratchcards;
+
+impl Solution for Scratchcards {
+    type Input = Vec<Card>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let cards = input.lines().map(|l| parse_all(input, l, parse_card)).collect::<Result<Vec<_>, _>>()?;
+        trace!("{:?}", cards);
+        Ok(cards)
+    }
 
-    let mut instance_counts: Vec<u64> = vec![1; cards.len()];
-    for (i, card) in cards.iter().enumerate() {
-        let matching_count = card.matching_count();
-        for j in i+1..i+1+matching_count as usize{
-            instance_counts[j] = instance_counts[j] + instance_counts[i];
+    fn part_one(cards: &Self::Input) -> u64 {
+        cards.iter().map(|c| c.score()).sum()
+    }
+
+    fn part_two(cards: &Self::Input) -> u64 {
+        let mut instance_counts: Vec<u64> = vec![1; cards.len()];
+        for (i, card) in cards.iter().enumerate() {
+            let matching_count = card.matching_count();
+            for j in i+1..i+1+matching_count as usize{
+                instance_counts[j] += instance_counts[i];
+            }
         }
+        instance_counts.iter().sum()
     }
-    let cards_count_part_2: u64 = instance_counts.iter().sum();
-    println!("Cards count [part 2]: {}", cards_count_part_2);
 }
@@ -0,0 +1,197 @@
+//! Solution to an Advent of Code problem, day 05, 2023
+//! https://adventofcode.com/2023/day/05
+
+use std::str::FromStr;
+
+use nom::error::ErrorKind;
+use nom::{Finish, IResult};
+use nom::bytes::complete::{tag, take_while1};
+use nom::character::complete::space1;
+use nom::multi::separated_list1;
+use nom::sequence::{terminated, tuple};
+
+use crate::intervals::{Interval, IntervalSet, OffsetMap};
+use crate::params::{Param, ParamInfo};
+use crate::{parse_all, ParseError, Solution};
+
+const MAPS: Param<usize> = Param::new("maps", 7, "Maps in the almanac, from seed to location");
+
+#[derive(Debug, PartialEq)]
+pub struct IntervalMap {
+    source_start: u64,
+    destination_start: u64,
+    length: u64,
+}
+
+fn parse_interval_map(input: &str) -> IResult<&str, IntervalMap> {
+    let (rest, (
+        destination_start, _, source_start, _, length
+    )) = tuple((nom::character::complete::u64,
+                space1,
+                nom::character::complete::u64,
+                space1,
+                nom::character::complete::u64))(input)?;
+
+    Ok((rest, IntervalMap {
+        source_start,
+        destination_start,
+        length,
+    }))
+}
+
+fn parse_seed_list(input: &str) -> IResult<&str, Vec<u64>> {
+    let (rest, (_, lst)) = tuple((tag("seeds: "), separated_list1(
+        space1,
+        nom::character::complete::u64,
+    )))(input)?;
+
+    Ok((rest, lst))
+}
+
+fn parse_map_title(input: &str) -> IResult<&str, &str> {
+    terminated(take_while1(|c: char| c.is_ascii_lowercase() || c == '-'), tag(" map:"))(input)
+}
+
+impl FromStr for IntervalMap {
+    type Err = nom::error::Error<String>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_interval_map(s).finish() {
+            Ok((_remaining, interval_map)) => Ok(interval_map),
+            Err(nom::error::Error { input, code }) => Err(nom::error::Error {
+                input: input.to_string(),
+                code,
+            })
+        }
+    }
+}
+
+impl IntervalMap {
+    /// Source interval and the offset that moves it onto the destination, None if either end is beyond `i64`.
+    fn piece(&self) -> Option<(Interval, i64)> {
+        let fits = |start: u64| start.checked_add(self.length).is_some_and(|end| i64::try_from(end).is_ok());
+        match fits(self.source_start) && fits(self.destination_start) {
+            true => Some((Interval::with_length(self.source_start as i64, self.length as i64),
+                          self.destination_start as i64 - self.source_start as i64)),
+            false => None,
+        }
+    }
+}
+
+// part 1
+fn interpret_numbers_as_individual_seeds(numbers: &[u64]) -> IntervalSet {
+    numbers.iter().map(|&n| Interval::with_length(n as i64, 1)).collect()
+}
+
+// part 2
+fn interpret_numbers_as_intervals_of_seeds(numbers: &[u64]) -> IntervalSet {
+    numbers.iter().array_chunks().map(|[&from, &length]| Interval::with_length(from as i64, length as i64)).collect()
+}
+
+fn lowest_location(seeds: IntervalSet, mappings: &[OffsetMap]) -> u64 {
+    let locations = mappings.iter().fold(seeds, |set, mapping| mapping.map_set(&set));
+    locations.min().expect("No seeds to plant") as u64
+}
+
+/// Whether the numbers are pairs of a start and a length, with no empty range, as part 2 reads them.
+fn are_seed_ranges(numbers: &[u64]) -> bool {
+    numbers.len().is_multiple_of(2) && numbers.iter().skip(1).step_by(2).all(|&length| length > 0)
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    numbers: Vec<u64>,
+    mappings: Vec<OffsetMap>,
+}
+
+pub struct SeedFertilizer;
+
+impl Solution for SeedFertilizer {
+    type Input = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const PARAMS: &'static [&'static dyn ParamInfo] = &[&MAPS];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let end = &input[input.len()..]; // in place of missing lines, so errors point to the end of input
+        let mut lines = input.lines();
+
+        let seeds = lines.next().unwrap_or(end);
+        // Part 1 takes any seeds, so part 2 checks that they are ranges itself
+        let numbers = parse_all(input, seeds, parse_seed_list)?;
+
+        lines.next(); // skip empty line
+        let mut mappings: Vec<OffsetMap> = vec![];
+        for _ in 0..MAPS.get() {
+            let mut pieces = vec![];
+            let title = lines.next().unwrap_or(end);
+            parse_all(input, title, parse_map_title)?;
+            for line in lines.by_ref().take_while(|l| !l.is_empty()) {
+                let piece = parse_all(input, line, parse_interval_map)?.piece();
+                pieces.push(piece.ok_or_else(|| ParseError::new(input, line, ErrorKind::TooLarge))?);
+            }
+            let mapping = OffsetMap::new(pieces).map_err(|_| ParseError::new(input, title, ErrorKind::Verify))?;
+            mappings.push(mapping);
+        }
+
+        Ok(Almanac { numbers, mappings })
+    }
+
+    fn part_one(almanac: &Self::Input) -> u64 {
+        lowest_location(interpret_numbers_as_individual_seeds(&almanac.numbers), &almanac.mappings)
+    }
+
+    fn part_two(almanac: &Self::Input) -> u64 {
+        assert!(are_seed_ranges(&almanac.numbers), "Seeds are not pairs of a start and a length above zero");
+        lowest_location(interpret_numbers_as_intervals_of_seeds(&almanac.numbers), &almanac.mappings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::params::Overrides;
+
+    #[test]
+    fn test_interval_map_from_str() {
+        assert_eq!(Ok(IntervalMap {
+            source_start: 5,
+            destination_start: 4,
+            length: 6,
+        }), "4 5 6".parse());
+
+        assert_eq!(Ok(IntervalMap {
+            source_start: 11,
+            destination_start: 0,
+            length: 42,
+        }), "0 11 42".parse());
+    }
+
+    #[test]
+    fn test_seeds_that_are_not_ranges() {
+        let mut overrides = Overrides::default();
+        overrides.set("maps", "1");
+        for (seeds, lowest) in [("seeds: 79", 79), ("seeds: 79 14 55", 14), ("seeds: 79 0", 0)] {
+            let input = format!("{}\n\nseed-to-soil map:\n50 98 2\n", seeds);
+            let almanac = overrides.scope(|| SeedFertilizer::parse(&input)).unwrap();
+            assert_eq!(SeedFertilizer::part_one(&almanac), lowest);
+            assert!(!are_seed_ranges(&almanac.numbers));
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_ranges_beyond_i64() {
+        let input = "seeds: 1 2\n\nseed-to-soil map:\n0 9223372036854775800 7\n0 9223372036854775807 1\n";
+        let e = SeedFertilizer::parse(input).unwrap_err();
+        assert_eq!((e.line, e.column, e.kind), (5, 1, ErrorKind::TooLarge));
+    }
+
+    #[test]
+    fn test_parse_rejects_overlapping_map() {
+        let input = "seeds: 1 2\n\nseed-to-soil map:\n50 98 2\n52 50 49\n";
+        let e = SeedFertilizer::parse(input).unwrap_err();
+        assert_eq!((e.line, e.column, e.kind), (3, 1, ErrorKind::Verify));
+    }
+}
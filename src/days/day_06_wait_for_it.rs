@@ -0,0 +1,131 @@
+//! Solution to an Advent of Code problem, day 06, 2023
+//! https://adventofcode.com/2023/day/06
+
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete::{space0, space1};
+use nom::error::ErrorKind;
+use nom::IResult;
+use nom::multi::separated_list1;
+use nom::sequence::tuple;
+
+use crate::math::isqrt_u128;
+use crate::{parse_all, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Game {
+    time: u64,
+    distance: u64,
+}
+
+
+/// In `u128`, as the distance of a long race is beyond `u64`.
+fn distance_travelled(holding_time: u64, time: u64) -> u128 {
+    let speed = holding_time as u128;
+    let travelling_time = (time - holding_time) as u128;
+    travelling_time * speed
+}
+
+impl Game {
+    /// Holding times `h` that win solve `h * (time - h) > distance`, a quadratic inequality
+    /// whose solutions lie symmetrically around `time / 2`.
+    fn ways_to_win(&self) -> u64 {
+        let discriminant = (self.time as u128).pow(2).saturating_sub(4 * self.distance as u128);
+        if discriminant == 0 {
+            return 0;
+        }
+
+        // The root rounded by isqrt can be off by one, so settle on the exact shortest winning time
+        let mut shortest = ((self.time as u128 - isqrt_u128(discriminant)) / 2) as u64;
+        while shortest > 0 && distance_travelled(shortest - 1, self.time) > self.distance as u128 {
+            shortest -= 1;
+        }
+        while shortest <= self.time / 2 && distance_travelled(shortest, self.time) <= self.distance as u128 {
+            shortest += 1;
+        }
+
+        let longest = self.time - shortest;
+        match shortest <= longest {
+            true => longest - shortest + 1,
+            false => 0,
+        }
+    }
+}
+
+fn parse_times(s: &str) -> IResult<&str, Vec<u64>> {
+    let (rest, (_, _, times)) = tuple((tag("Time:"), space0, separated_list1(space1, nom::character::complete::u64)))(s)?;
+    Ok((rest, times))
+}
+
+fn parse_distances(s: &str) -> IResult<&str, Vec<u64>> {
+    let (rest, (_, _, distances)) = tuple((tag("Distance:"), space0, separated_list1(space1, nom::character::complete::u64)))(s)?;
+    Ok((rest, distances))
+}
+
+/// Kerning fix for part 2: "7  15   30" is a single number 71530. An error pointing at `line` if it is too large.
+fn ignore_spaces(input: &str, line: &str, numbers: &[u64]) -> Result<u64, ParseError> {
+    numbers.iter().join("").parse().map_err(|_| ParseError::new(input, line, ErrorKind::TooLarge))
+}
+
+pub struct Races {
+    games: Vec<Game>,
+    games_ignoring_spaces: Vec<Game>,
+}
+
+pub struct WaitForIt;
+
+impl Solution for WaitForIt {
+    type Input = Races;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let end = &input[input.len()..]; // in place of missing lines, so errors point to the end of input
+        let mut lines = input.lines();
+        let (time_line, distance_line) = (lines.next().unwrap_or(end), lines.next().unwrap_or(end));
+        let times = parse_all(input, time_line, parse_times)?;
+        let distances = parse_all(input, distance_line, parse_distances)?;
+        let time = ignore_spaces(input, time_line, &times)?;
+        let distance = ignore_spaces(input, distance_line, &distances)?;
+
+        Ok(Races {
+            games: times.iter().zip(&distances).map(|(&time, &distance)| Game { time, distance }).collect_vec(),
+            // Part 2: reread the numbers, ignoring spaces
+            games_ignoring_spaces: vec![Game { time, distance }],
+        })
+    }
+
+    fn part_one(races: &Self::Input) -> u64 {
+        races.games.iter().map(|g| g.ways_to_win()).product()
+    }
+
+    fn part_two(races: &Self::Input) -> u64 {
+        races.games_ignoring_spaces.iter().map(|g| g.ways_to_win()).product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_too_large_kerned_numbers() {
+        let e = WaitForIt::parse("Time:      7  15   30\nDistance:  9  40  200  9999999999  9999999999\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.kind), (2, 1, ErrorKind::TooLarge));
+    }
+
+    #[test]
+    fn test_ways_to_win() {
+        for (time, distance) in [(7, 9), (15, 40), (30, 200), (71530, 940200), (4, 4), (5, 6), (1, 0), (0, 0), (10, 100)] {
+            let brute_force = (0..time).filter(|&h| distance_travelled(h, time) > distance as u128).count() as u64;
+            assert_eq!(Game { time, distance }.ways_to_win(), brute_force, "time {}, distance {}", time, distance);
+        }
+    }
+
+    #[test]
+    fn test_long_race() {
+        // Holding for half the time goes about 8.5e37 mm, far beyond u64; all but the first two and last two win
+        assert_eq!(Game { time: u64::MAX - 1, distance: u64::MAX }.ways_to_win(), u64::MAX - 4);
+        assert_eq!(Game { time: 1 << 40, distance: (1 << 40) - 1 }.ways_to_win(), (1 << 40) - 3);
+    }
+}
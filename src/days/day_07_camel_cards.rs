@@ -1,9 +1,7 @@
-use std::cmp::Ordering;
-/// Solution to an Advent of Code problem, day 07, 2023
-/// https://adventofcode.com/2023/day/07
+//! Solution to an Advent of Code problem, day 07, 2023
+//! https://adventofcode.com/2023/day/07
 
-use std::env;
-use std::fs;
+use std::cmp::Ordering;
 use std::str::FromStr;
 use itertools::Itertools;
 
@@ -14,6 +12,8 @@ use nom::IResult;
 use nom::multi::fill;
 use nom::sequence::tuple;
 
+use crate::{parse_all, ParseError, Solution};
+
 #[derive(PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Debug)]
 enum Card {
     Null,
@@ -62,12 +62,12 @@ enum HandType {
 }
 
 #[derive(PartialEq, Eq, Debug)]
-struct Hand {
+pub struct Hand {
     cards: [Card; 5],
 }
 
 #[derive(PartialEq, Eq, Debug)]
-struct HandWithJoker {
+pub struct HandWithJoker {
     cards: [CardWithJoker; 5],
 }
 
@@ -148,13 +148,13 @@ fn parse_card_with_joker(s: &str) -> IResult<&str, CardWithJoker> {
 fn parse_hand(s: &str) -> IResult<&str, Hand> {
     let mut cards: [Card; 5] = [Card::Null; 5];
     let (rest, ()) = fill(parse_card, &mut cards)(s)?;
-    Ok((&rest, Hand { cards }))
+    Ok((rest, Hand { cards }))
 }
 
 fn parse_hand_with_joker(s: &str) -> IResult<&str, HandWithJoker> {
     let mut cards: [CardWithJoker; 5] = [CardWithJoker::Null; 5];
     let (rest, ()) = fill(parse_card_with_joker, &mut cards)(s)?;
-    Ok((&rest, HandWithJoker { cards }))
+    Ok((rest, HandWithJoker { cards }))
 }
 
 impl FromStr for Hand {
@@ -198,7 +198,7 @@ impl Hand {
         frequencies.sort();
         frequencies.reverse();
 
-        match (frequencies[0].clone(), frequencies[1].clone()) {
+        match (frequencies[0], frequencies[1]) {
             (Some((5, _)), _) => HandType::FiveOfAKind,
             (Some((4, _)), _) => HandType::FourOfAKind,
             (Some((3, _)), Some((2, _))) => HandType::FullHouse,
@@ -217,7 +217,7 @@ impl HandWithJoker {
 
         for card in self.cards {
             if card == CardWithJoker::J {
-                joker_count = joker_count + 1;
+                joker_count += 1;
                 continue;
             }
             for i in 0..5 {
@@ -234,7 +234,7 @@ impl HandWithJoker {
         frequencies.sort();
         frequencies.reverse();
 
-        match (frequencies[0].clone(), frequencies[1].clone(), joker_count) {
+        match (frequencies[0], frequencies[1], joker_count) {
             (Some((5, _)), _, 0) => HandType::FiveOfAKind,
             (Some((4, _)), _, 1) => HandType::FiveOfAKind,
             (Some((3, _)), _, 2) => HandType::FiveOfAKind,
@@ -265,35 +265,53 @@ impl HandWithJoker {
     }
 }
 
-fn parse_line(s: &str) -> (Hand, u64) {
-    let (_, (hand, _, bet)) = tuple((parse_hand, space1, nom::character::complete::u64))(s).unwrap();
-    (hand, bet)
+fn parse_line(s: &str) -> IResult<&str, (Hand, u64)> {
+    let (rest, (hand, _, bet)) = tuple((parse_hand, space1, nom::character::complete::u64))(s)?;
+    Ok((rest, (hand, bet)))
 }
 
-fn parse_line_with_jokers(s: &str) -> (HandWithJoker, u64) {
-    let (_, (hand, _, bet)) = tuple((parse_hand_with_joker, space1, nom::character::complete::u64))(s).unwrap();
-    (hand, bet)
+fn parse_line_with_jokers(s: &str) -> IResult<&str, (HandWithJoker, u64)> {
+    let (rest, (hand, _, bet)) = tuple((parse_hand_with_joker, space1, nom::character::complete::u64))(s)?;
+    Ok((rest, (hand, bet)))
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
-    let contents = fs::read_to_string(filename).expect("Cannot read file");
-
-    let mut players = contents.clone().lines().map(parse_line).collect_vec();
+fn total_winnings<H: Ord>(players: &[(H, u64)]) -> u64 {
+    let mut players = players.iter().collect_vec();
     players.sort_by(|a, b| a.0.cmp(&b.0));
-    let total_winnings_part_1: u64 = players.iter().enumerate().map(|(rank, player)| (rank as u64 + 1) * player.1).sum();
-    println!("Total winnings [part 1]: {}", total_winnings_part_1);
+    players.iter().enumerate().map(|(rank, player)| (rank as u64 + 1) * player.1).sum()
+}
 
-    let mut players = contents.clone().lines().map(parse_line_with_jokers).collect_vec();
-    players.sort_by(|a, b| a.0.cmp(&b.0));
-    let total_winnings_part_2: u64 = players.iter().enumerate().map(|(rank, player)| (rank as u64 + 1) * player.1).sum();
-    println!("Total winnings [part 2]: {}", total_winnings_part_2);
+pub struct Players {
+    plain: Vec<(Hand, u64)>,
+    with_jokers: Vec<(HandWithJoker, u64)>,
+}
+
+pub struct CamelCards;
+
+impl Solution for CamelCards {
+    type Input = Players;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Players {
+            plain: input.lines().map(|l| parse_all(input, l, parse_line)).collect::<Result<Vec<_>, _>>()?,
+            with_jokers: input.lines().map(|l| parse_all(input, l, parse_line_with_jokers)).collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+
+    fn part_one(players: &Self::Input) -> u64 {
+        total_winnings(&players.plain)
+    }
+
+    fn part_two(players: &Self::Input) -> u64 {
+        total_winnings(&players.with_jokers)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use itertools::assert_equal;
+    
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use super::*;
 
@@ -328,4 +346,4 @@ mod tests {
         assert_eq!(HandWithJoker::from_str("KTJJT").unwrap().hand_type(), HandType::FourOfAKind);
         assert_eq!(HandWithJoker::from_str("QQQJA").unwrap().hand_type(), HandType::FourOfAKind);
     }
-}
\ No newline at end of file
+}
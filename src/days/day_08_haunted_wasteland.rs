@@ -0,0 +1,271 @@
+//! Solution to an Advent of Code problem, day 08, 2023
+//! https://adventofcode.com/2023/day/08
+
+use itertools::Itertools;
+use log::debug;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_while_m_n};
+use nom::combinator::value;
+use nom::error::ErrorKind;
+use nom::IResult;
+use nom::multi::many1;
+
+use crate::cancel::checkpoint;
+use crate::cycles::{find_cycle_hashed, Cycle};
+use crate::math::crt;
+use crate::{parse_all, ParseError, Solution};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct NextNodes {
+    left: [u8; 3],
+    right: [u8; 3],
+}
+
+fn name_to_index(name: &[u8; 3]) -> usize {
+    ((name[0] as usize * 255usize) + name[1] as usize) * 255usize + name[2] as usize
+}
+
+fn ends_with_a(name: &[u8; 3]) -> bool {
+    name[2] == b'A'
+}
+
+fn ends_with_z(name: &[u8; 3]) -> bool {
+    name[2] == b'Z'
+}
+
+#[cfg(test)]
+fn index_to_name(i: usize) -> [u8; 3] {
+    let mut out = [0u8; 3];
+    let mut remainder = i;
+
+    out[2] = (remainder % 255) as u8;
+    remainder /= 255;
+    out[1] = (remainder % 255) as u8;
+    remainder /= 255;
+    out[0] = remainder as u8;
+    out
+}
+
+fn parse_direction(i: &str) -> IResult<&str, Direction> {
+    alt((
+        value(Direction::Left, tag("L")),
+        value(Direction::Right, tag("R"))
+    ))(i)
+}
+
+fn parse_directions(i: &str) -> IResult<&str, Vec<Direction>> {
+    many1(parse_direction)(i)
+}
+
+fn parse_node_name(i: &str) -> IResult<&str, &str> {
+    take_while_m_n(3, 3, |c: char| c.is_ascii_alphanumeric())(i)
+}
+
+fn to_name(s: &str) -> [u8; 3] {
+    s.as_bytes().try_into().expect("Node names have three letters")
+}
+
+/// A node and the two it leads to, as slices of the line, so errors can point at them.
+fn parse_node(i: &str) -> IResult<&str, (&str, &str, &str)> {
+    let (rest, from) = parse_node_name(i)?;
+    let (rest, _) = tag(" = (")(rest)?;
+    let (rest, left) = parse_node_name(rest)?;
+    let (rest, _) = tag(", ")(rest)?;
+    let (rest, right) = parse_node_name(rest)?;
+    let (rest, _) = tag(")")(rest)?;
+
+    Ok((rest, (from, left, right)))
+}
+
+pub struct Network {
+    directions: Vec<Direction>,
+    nodes: Vec<Option<NextNodes>>,
+    all_node_names: Vec<[u8; 3]>,
+}
+
+pub struct HauntedWasteland;
+
+impl Solution for HauntedWasteland {
+    type Input = Network;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines();
+
+        let mut all_node_names = vec![];
+        let directions = parse_all(input, lines.next().unwrap_or(input), parse_directions)?;
+        lines.next(); // Empty line
+        let mut nodes: Vec<Option<NextNodes>> = vec![None; 255 * 255 * 255];
+        let mut references = vec![];
+        let mut first_node = None;
+        for line in lines {
+            first_node.get_or_insert(line);
+            let (from, left, right) = parse_all(input, line, parse_node)?;
+            nodes[name_to_index(&to_name(from))] = Some(NextNodes { left: to_name(left), right: to_name(right) });
+            all_node_names.push(to_name(from));
+            references.extend([left, right]);
+        }
+
+        // Walks must never reach an undefined node, and part 2 needs a ghost to walk. Part 1 starts at AAA,
+        // which ends with A too, but examples of part 2 have no AAA, so part 1 checks for it itself.
+        if let Some(undefined) = references.iter().find(|name| nodes[name_to_index(&to_name(name))].is_none()) {
+            return Err(ParseError::new(input, undefined, ErrorKind::Verify));
+        }
+        if !all_node_names.iter().any(ends_with_a) {
+            return Err(ParseError::new(input, first_node.unwrap_or(input), ErrorKind::Verify));
+        }
+
+        Ok(Network { directions, nodes, all_node_names })
+    }
+
+    fn part_one(network: &Self::Input) -> u64 {
+        let mut step_count_part_1 = 0u64;
+        let aaa: [u8; 3] = "AAA".as_bytes().try_into().unwrap();
+        let zzz: [u8; 3] = "ZZZ".as_bytes().try_into().unwrap();
+        assert!(network.nodes[name_to_index(&aaa)].is_some(), "No node AAA to start part 1 at");
+        let mut position: [u8; 3] = aaa;
+        let mut direction_index = 0usize;
+        while position != zzz {
+            checkpoint();
+            step_count_part_1 += 1;
+            position = match network.directions[direction_index] {
+                Direction::Left => network.nodes[name_to_index(&position)].unwrap().left,
+                Direction::Right => network.nodes[name_to_index(&position)].unwrap().right,
+            };
+
+            direction_index = (direction_index + 1) % network.directions.len();
+        }
+
+        step_count_part_1
+    }
+
+    fn part_two(network: &Self::Input) -> usize {
+        let starts_for_part_2 = network.all_node_names.iter().filter_map(|f| match ends_with_a(f) {
+            true => Some(*f),
+            _ => None
+        }).collect_vec();
+
+        let mut schedules = vec![];
+        for start_node in starts_for_part_2 {
+            let schedule = z_schedule(network, start_node);
+
+            // On my input every ghost has a single Z node at the very end of its cycle, and all cycle lengths
+            // are multiples of the directions length (AAA: 17873 = 293 * 61), so the answer is just their lcm.
+            // The input in advent of caledar 2023, is apparently well crafted. The general case is handled anyway.
+            debug!("Cycle of {}: tail {}, length {}, Z nodes at steps {:?}", String::from_utf8_lossy(&start_node),
+                   schedule.cycle.tail, schedule.cycle.length, schedule.z_steps);
+            schedules.push(schedule);
+        }
+
+        first_common_z_step(&schedules).expect("Ghosts never all stand on Z nodes at once")
+    }
+}
+
+/// Steps at which a ghost stands on a Z node, all of them found in its first `tail + length` steps.
+struct ZSchedule {
+    cycle: Cycle,
+    z_steps: Vec<usize>,
+}
+
+fn z_schedule(network: &Network, start_node: [u8; 3]) -> ZSchedule {
+    let step = |&(direction_index, position): &(usize, [u8; 3])| {
+        checkpoint();
+        let next_nodes = network.nodes[name_to_index(&position)].unwrap();
+        let position = match network.directions[direction_index] {
+            Direction::Left => next_nodes.left,
+            Direction::Right => next_nodes.right,
+        };
+        ((direction_index + 1) % network.directions.len(), position)
+    };
+
+    let (cycle, states) = find_cycle_hashed(&(0, start_node), step);
+    let z_steps = states.iter().positions(|(_, position)| ends_with_z(position)).collect_vec();
+    ZSchedule { cycle, z_steps }
+}
+
+/// First step, after the start, when all ghosts stand on Z nodes at once.
+fn first_common_z_step(schedules: &[ZSchedule]) -> Option<usize> {
+    let all_on_z = |n: usize| schedules.iter().all(|s| s.z_steps.contains(&s.cycle.equivalent_step(n)));
+
+    // While some ghost is still in its tail, try steps one by one
+    let longest_tail = schedules.iter().map(|s| s.cycle.tail).max()?;
+    if let Some(n) = (1..longest_tail).find(|&n| all_on_z(n)) {
+        return Some(n);
+    }
+
+    // From then on each ghost is on a Z node at some residues modulo its cycle length.
+    // Every choice of one residue per ghost gives a system of congruences.
+    let residues = schedules.iter().map(|s| s.z_steps.iter()
+        .filter(|&&z| z >= s.cycle.tail)
+        .map(|&z| (z as u128, s.cycle.length as u128))
+        .collect_vec());
+    residues.multi_cartesian_product()
+        .filter_map(|congruences| {
+            checkpoint();
+            crt(&congruences).ok()
+        })
+        .map(|(x, m)| {
+            // Smallest solution not before the longest tail
+            let lowest = longest_tail.max(1) as u128;
+            let n = match x < lowest {
+                true => x + (lowest - x).div_ceil(m) * m,
+                false => x,
+            };
+            n as usize
+        })
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_undefined_nodes() {
+        let e = HauntedWasteland::parse("LR\n\nAAA = (BBB, ZZZ)\nBBB = (CCC, ZZZ)\nZZZ = (ZZZ, ZZZ)").err().unwrap();
+        assert_eq!((e.line, e.column, e.kind), (4, 8, ErrorKind::Verify));
+        // No ghost to start part 2 with
+        let e = HauntedWasteland::parse("LR\n\n11B = (11Z, 11Z)\n11Z = (11B, 11B)").err().unwrap();
+        assert_eq!((e.line, e.column, e.kind), (3, 1, ErrorKind::Verify));
+    }
+
+    #[test]
+    fn test_part_two_without_aaa() {
+        let network = HauntedWasteland::parse("LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n\
+            22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)").unwrap();
+        assert_eq!(HauntedWasteland::part_two(&network), 6);
+    }
+
+    #[test]
+    fn test_to_from_name() {
+        let abc: [u8; 3] = "ABC".as_bytes().try_into().unwrap();
+        let def: [u8; 3] = "DEF".as_bytes().try_into().unwrap();
+        assert_eq!(index_to_name(name_to_index(&abc)), abc);
+        assert_eq!(index_to_name(name_to_index(&def)), def);
+        assert_ne!(name_to_index(&abc), name_to_index(&def));
+    }
+
+    #[test]
+    fn test_ends_with_a() {
+        let cba: [u8; 3] = "CBA".as_bytes().try_into().unwrap();
+        let def: [u8; 3] = "DEF".as_bytes().try_into().unwrap();
+        assert!(ends_with_a(&cba));
+        assert!(!ends_with_a(&def));
+    }
+
+    #[test]
+    fn test_ends_with_z() {
+        let cba: [u8; 3] = "CBA".as_bytes().try_into().unwrap();
+        let xyz: [u8; 3] = "XYZ".as_bytes().try_into().unwrap();
+        assert!(!ends_with_z(&cba));
+        assert!(ends_with_z(&xyz));
+    }
+}
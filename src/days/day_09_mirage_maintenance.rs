@@ -1,13 +1,12 @@
-/// Solution to an Advent of Code problem, day 09, 2023
-/// https://adventofcode.com/2023/day/09
+//! Solution to an Advent of Code problem, day 09, 2023
+//! https://adventofcode.com/2023/day/09
 
-use std::env;
-use std::fs;
-use itertools::Itertools;
 use nom::character::complete::space1;
 use nom::IResult;
 use nom::multi::separated_list0;
 
+use crate::{parse_all, ParseError, Solution};
+
 fn extrapolate(history: &[i64]) -> i64 {
     let mut histories: Vec<Vec<i64>> = vec![history.to_vec()];
 
@@ -24,7 +23,7 @@ fn extrapolate(history: &[i64]) -> i64 {
     // Extrapolate up
     let mut next = 0;
     for history in histories.iter().rev().skip(1) {
-        next = history.last().unwrap() + next;
+        next += history.last().unwrap();
     }
 
     next
@@ -57,17 +56,24 @@ fn parse_list(i: &str) -> IResult<&str, Vec<i64>> {
 }
 
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
-    let contents = fs::read_to_string(filename).expect("Cannot read file");
-    let lines = contents.lines().map(|l| parse_list(l).unwrap().1).collect_vec();
+pub struct MirageMaintenance;
+
+impl Solution for MirageMaintenance {
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
 
-    let sum_of_extrapolations_part_1: i64 = lines.iter().map(|v| extrapolate(v)).sum();
-    println!("Sum of extrapolations [part 1]: {}", sum_of_extrapolations_part_1);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(|l| parse_all(input, l, parse_list)).collect()
+    }
 
-    let sum_of_back_extrapolations_part_2: i64 = lines.iter().map(|v| extrapolate_back(v)).sum();
-    println!("Sum of back extrapolations [part 2]: {}", sum_of_back_extrapolations_part_2);
+    fn part_one(histories: &Self::Input) -> i64 {
+        histories.iter().map(|v| extrapolate(v)).sum()
+    }
+
+    fn part_two(histories: &Self::Input) -> i64 {
+        histories.iter().map(|v| extrapolate_back(v)).sum()
+    }
 }
 
 #[cfg(test)]
@@ -77,11 +83,11 @@ mod tests {
 
     #[test]
     fn test_extrapolate() {
-        assert_eq!(extrapolate(&vec![0, 3, 6, 9, 12, 15]), 18);
+        assert_eq!(extrapolate(&[0, 3, 6, 9, 12, 15]), 18);
     }
 
     #[test]
     fn test_extrapolate_back() {
-        assert_eq!(extrapolate_back(&vec![10, 13, 16, 21, 30, 45]), 5);
+        assert_eq!(extrapolate_back(&[10, 13, 16, 21, 30, 45]), 5);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,150 @@
+//! Solution to an Advent of Code problem, day 10, 2023
+//! https://adventofcode.com/2023/day/10
+
+use log::{debug, trace};
+use nom::AsChar;
+
+use crate::grid::Grid;
+use crate::{ParseError, Solution};
+
+/// Directions, as `(dx, dy)`, in which a pipe connects to its neighbours.
+fn openings(symbol: u8) -> &'static [(isize, isize)] {
+    match symbol {
+        b'|' => &[(0, -1), (0, 1)],
+        b'-' => &[(-1, 0), (1, 0)],
+        b'L' => &[(0, -1), (1, 0)],
+        b'J' => &[(0, -1), (-1, 0)],
+        b'7' => &[(0, 1), (-1, 0)],
+        b'F' => &[(0, 1), (1, 0)],
+        b'S' => &[(0, -1), (1, 0), (0, 1), (-1, 0)],
+        _ => &[],
+    }
+}
+
+/// Whether the pipes on two neighbouring cells lead into each other.
+fn connected(map: &Grid<u8>, from: (usize, usize), to: (usize, usize)) -> bool {
+    let (dx, dy) = (to.0 as isize - from.0 as isize, to.1 as isize - from.1 as isize);
+    openings(map[from]).contains(&(dx, dy)) && openings(map[to]).contains(&(-dx, -dy))
+}
+
+/// Follows the pipes from "S" until it gets back to it. Returned path starts and ends with "S".
+fn find_loop(map: &Grid<u8>) -> Vec<(usize, usize)> {
+    let start_coords = map.iter().find(|(_, &c)| c == b'S').unwrap().0;
+
+    let mut current_position = start_coords;
+    let mut path = vec![start_coords];
+    loop {
+        let next_step = map.neighbours4(current_position.0, current_position.1)
+            .find(|&n| connected(map, current_position, n) && (path.len() < 2 || path[path.len() - 2] != n))
+            .unwrap();
+
+        current_position = next_step;
+        path.push(next_step);
+        if next_step == start_coords {
+            break;
+        }
+    }
+
+    path
+}
+
+pub struct PipeMaze;
+
+impl Solution for PipeMaze {
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_one(map: &Self::Input) -> usize {
+        let path = find_loop(map);
+
+        trace!("Path: {:?}", path);
+        debug!("Path length: {}", path.len());
+        path.len() / 2
+    }
+
+    fn part_two(map: &Self::Input) -> u64 {
+        let path = find_loop(map);
+        let start_coords = path[0];
+
+        // Fill in "S" at start coords with actual symbol:
+        let start_out = path[1]; // First after start
+        let delta_out = (start_out.0 as i64 - start_coords.0 as i64, start_out.1 as i64 - start_coords.1 as i64);
+        let start_in = path[path.len() - 2]; // Last before start
+        let delta_in = (start_coords.0 as i64 - start_in.0 as i64, start_coords.1 as i64 - start_in.1 as i64);
+
+        let start_symbol = match (delta_in, delta_out) {
+            ((1, 0), (1, 0)) => b'-', /*-S-*/
+            ((-1, 0), (-1, 0)) => b'-', /*-S-*/
+            ((0, 1), (0, 1)) => b'|',
+            ((0, -1), (0, -1)) => b'|',
+
+            ((1, 0), (0, 1)) => b'7',
+            ((0, -1), (-1, 0)) => b'7',
+            ((0, 1), (1, 0)) => b'L',
+            ((-1, 0), (0, -1)) => b'L',
+            ((1, 0), (0, -1)) => b'J',
+            ((0, 1), (-1, 0)) => b'J',
+            ((0, -1), (1, 0)) => b'F',
+            ((-1, 0), (0, 1)) => b'F',
+
+            (a, b) => panic!("Unrecognized case: ({:?}, {:?})!", a, b)
+        };
+        debug!("Start symbol: {}", start_symbol.as_char());
+
+        // Counting inside area of the loop
+        let mut colors = Grid::new(map.width(), map.height(), b'.');
+
+        // Mark path itself
+        for (x, y) in path {
+            colors[(x, y)] = match map[(x, y)] {
+                b'S' => start_symbol,
+                s => s
+            };
+        }
+
+        // ray-trace, row by row
+        // The idea behind the algorithm is from https://elixirforum.com/t/advent-of-code-2023-day-10/60279/4
+        for y in 0..colors.height() {
+            let mut inside = false;
+            let mut corners = vec![];
+            for x in 0..colors.width() {
+                if colors[(x, y)] == b'.' {
+                    colors[(x, y)] = match inside {
+                        true => b'I',
+                        false => b'.'
+                    };
+                } else if colors[(x, y)] == b'-' {
+                    // This case is horrendously uninteresting in horizontal ray trace.
+                } else if colors[(x, y)] == b'|' {
+                    inside = !inside;
+                } else {
+                    corners.push(colors[(x, y)]);
+                    if corners.len() >= 2 {
+                        let flip_inside_outside = match (corners[corners.len() - 2], corners[corners.len() - 1]) {
+                            (b'L', b'7') => true,
+                            (b'L', b'J') => false,
+                            (b'F', b'7') => false,
+                            (b'F', b'J') => true,
+                            _ => false
+                        };
+                        if flip_inside_outside {
+                            inside = !inside;
+                        }
+                    }
+                }
+            }
+        }
+
+
+        trace!("Map with inside cells marked I:\n{}", colors);
+
+        // Count INSIDE cells
+        colors.iter().filter(|(_, &v)| v == b'I').count() as u64
+    }
+}
+
@@ -0,0 +1,79 @@
+//! Solution to an Advent of Code problem, day 11, 2023
+//! https://adventofcode.com/2023/day/11
+
+use itertools::Itertools;
+
+use crate::grid::Grid;
+use crate::params::{Param, ParamInfo};
+use crate::{ParseError, Solution};
+
+const EXPANSION_1: Param<usize> = Param::new("expansion_1", 2, "Expansion factor of empty rows and columns in part 1");
+const EXPANSION_2: Param<usize> = Param::new("expansion_2", 1_000_000, "Expansion factor of empty rows and columns in part 2");
+
+fn expand_cosmos(c: &Grid<u8>, expansion_factor: usize) -> (Vec<usize>, Vec<usize>) {
+    let expand = |empty: Vec<bool>| empty.iter()
+        .scan(0usize, |mapped, &empty| {
+            let position = *mapped;
+            *mapped += if empty { expansion_factor } else { 1 };
+            Some(position)
+        })
+        .collect_vec();
+
+    let empty_columns = c.columns().map(|mut column| column.all(|&v| v == b'.')).collect_vec();
+    let empty_rows = c.rows().map(|row| row.iter().all(|&v| v == b'.')).collect_vec();
+    (expand(empty_columns), expand(empty_rows))
+}
+
+fn find_galaxies(c: &Grid<u8>, position_mapping: (Vec<usize>, Vec<usize>)) -> Vec<(usize, usize)> {
+    c.iter()
+        .filter(|(_, &v)| v == b'#')
+        .map(|((x, y), _)| (position_mapping.0[x], position_mapping.1[y]))
+        .collect()
+}
+
+fn sum_distances(galaxies: Vec<(usize, usize)>) -> usize {
+    let mut distance_matrix = vec![vec![0; galaxies.len()]; galaxies.len()];
+    for i in 0..galaxies.len() {
+        for j in 0..galaxies.len() {
+            if i != j {
+                let distance = galaxies[i].0.abs_diff(galaxies[j].0) + galaxies[i].1.abs_diff(galaxies[j].1);
+                distance_matrix[i][j] = distance;
+                distance_matrix[j][i] = distance;
+            }
+        }
+    }
+
+    let mut paths_sum = 0usize;
+    for i in 0..galaxies.len() {
+        for j in i..galaxies.len() {
+            paths_sum += distance_matrix[i][j];
+        }
+    }
+    paths_sum
+}
+
+pub struct CosmicExpansion;
+
+impl Solution for CosmicExpansion {
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PARAMS: &'static [&'static dyn ParamInfo] = &[&EXPANSION_1, &EXPANSION_2];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_one(cosmos: &Self::Input) -> usize {
+        let position_mapping_1 = expand_cosmos(cosmos, EXPANSION_1.get());
+        let galaxies_1 = find_galaxies(cosmos, position_mapping_1);
+        sum_distances(galaxies_1)
+    }
+
+    fn part_two(cosmos: &Self::Input) -> usize {
+        let position_mapping_2 = expand_cosmos(cosmos, EXPANSION_2.get());
+        let galaxies_2 = find_galaxies(cosmos, position_mapping_2);
+        sum_distances(galaxies_2)
+    }
+}
@@ -1,9 +1,8 @@
-/// Solution to an Advent of Code problem, day 12, 2023
-/// https://adventofcode.com/2023/day/12
+//! Solution to an Advent of Code problem, day 12, 2023
+//! https://adventofcode.com/2023/day/12
 
-use std::env;
-use std::fs;
 use itertools::Itertools;
+use log::{debug, trace};
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::space1;
@@ -13,25 +12,31 @@ use nom::multi::{many1, separated_list0};
 use nom::sequence::tuple;
 use rayon::prelude::*;
 
+use crate::cancel::{checkpoint, CancelToken};
+use crate::params::{Param, ParamInfo};
+use crate::{parse_all, ParseError, Solution};
+
+const UNFOLD: Param<usize> = Param::new("unfold", 5, "Copies of each record after unfolding, in part 2");
+
 #[derive(Debug, Copy, Clone, PartialEq)]
-enum Spring {
+pub enum Spring {
     Damaged,
     Operational,
     Unknown,
 }
 
 #[derive(Debug, PartialEq)]
-struct Record {
+pub struct Record {
     springs: Vec<Spring>,
     groups: Vec<u64>,
 }
 
 impl Record {
-    fn unfold(&self) -> Record {
-        let new_springs = vec![self.springs.clone(); 5].join(&Spring::Unknown);
+    fn unfold(&self, copies: usize) -> Record {
+        let new_springs = vec![self.springs.clone(); copies].join(&Spring::Unknown);
         Record {
             springs: new_springs,
-            groups: self.groups.repeat(5),
+            groups: self.groups.repeat(copies),
         }
     }
 
@@ -110,12 +115,12 @@ fn parse_numbers(i: &str) -> IResult<&str, Vec<u64>> {
     separated_list0(tag(","), nom::character::complete::u64)(i)
 }
 
-fn parse_record(i: &str) -> Record {
-    let (_, (springs, _, groups)) = tuple((many1(parse_spring), space1, parse_numbers))(i).unwrap();
-    Record { springs, groups }
+fn parse_record(i: &str) -> IResult<&str, Record> {
+    let (rest, (springs, _, groups)) = tuple((many1(parse_spring), space1, parse_numbers))(i)?;
+    Ok((rest, Record { springs, groups }))
 }
 
-fn is_valid(springs: &[Spring], groups: &Vec<u64>) -> bool {
+fn is_valid(springs: &[Spring], groups: &[u64]) -> bool {
     let mut groups_in_s = Vec::with_capacity(groups.len());
     let mut current_group_length = 0;
     for s in springs {
@@ -134,7 +139,7 @@ fn is_valid(springs: &[Spring], groups: &Vec<u64>) -> bool {
 }
 
 
-fn is_valid_till_first_unknown(springs: &[Spring], groups: &Vec<u64>) -> bool {
+fn is_valid_till_first_unknown(springs: &[Spring], groups: &[u64]) -> bool {
     let mut damaged_count = 0u64;
     for s in springs {
         if *s == Spring::Damaged {
@@ -164,11 +169,10 @@ fn is_valid_till_first_unknown(springs: &[Spring], groups: &Vec<u64>) -> bool {
             break;
         }
     }
-    if current_group_length > 0 {
-        if !(current_group_index < groups.len() && groups[current_group_index] == current_group_length) {
+    if current_group_length > 0
+        && !(current_group_index < groups.len() && groups[current_group_index] == current_group_length) {
             return false;
         }
-    }
 
     // Backward
     let mut current_group_length = 0;
@@ -188,11 +192,10 @@ fn is_valid_till_first_unknown(springs: &[Spring], groups: &Vec<u64>) -> bool {
             break;
         }
     }
-    if current_group_length > 0 {
-        if !(groups.len() > current_group_index && groups[groups.len() - 1 - current_group_index] == current_group_length) {
+    if current_group_length > 0
+        && !(groups.len() > current_group_index && groups[groups.len() - 1 - current_group_index] == current_group_length) {
             return false;
         }
-    }
 
     true
 }
@@ -206,6 +209,7 @@ fn count_combinations_brute_force(r: &Record) -> u64 {
     let unknown_springs_count = r.springs.iter().filter(|&&s| s == Spring::Unknown).count() as u32;
     let combination_count = 2u64.pow(unknown_springs_count);
     for c in 0..combination_count {
+        checkpoint();
         let mut d = c;
 
         let mut springs = r.springs.clone();
@@ -229,6 +233,7 @@ fn count_combinations_brute_force(r: &Record) -> u64 {
 }
 
 fn count_combinations_recursive(r: &Record) -> u64 {
+    checkpoint();
     if r.is_resolved() {
         return match is_valid(&r.springs, &r.groups) {
             true => 1,
@@ -253,75 +258,84 @@ fn count_combinations_recursive(r: &Record) -> u64 {
     count
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
-    let contents = fs::read_to_string(filename).expect("Cannot read file");
-    let records = contents.lines().map(parse_record).collect_vec();
-
-    let combinations_sum = records.iter().map(count_combinations_brute_force).sum::<u64>();
-    println!("Combinations sum [part 1]: {}", combinations_sum);
-
-    let combinations_sum = records.iter().map(count_combinations_recursive).sum::<u64>();
-    println!("Combinations sum [part 1, recursive]: {}", combinations_sum);
-
-    // For part 2, brute force will not be fast enough.
-    // After analysing the input, there can be up to 75 damaged springs to allocate between
-
-    let unfolded_records = records.iter().map(|r| r.unfold()).collect_vec();
-
-    // Statistics in preparation for part 2.
-    let mut max_unknown_damaged_count = 0;
-    let mut max_unknown_count = 0;
-    for r in unfolded_records {
-        let mut unknown_count = 0u64;
-        let mut damaged_count = 0u64;
-        let mut operational_count = 0u64;
-        for s in r.springs {
-            match s {
-                Spring::Operational => { operational_count += 1; }
-                Spring::Damaged => { damaged_count += 1; }
-                Spring::Unknown => { unknown_count += 1; }
+pub struct HotSprings;
+
+impl Solution for HotSprings {
+    type Input = Vec<Record>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const PARAMS: &'static [&'static dyn ParamInfo] = &[&UNFOLD];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(|l| parse_all(input, l, parse_record)).collect()
+    }
+
+    fn part_one(records: &Self::Input) -> u64 {
+        records.iter().map(count_combinations_brute_force).sum::<u64>()
+    }
+
+    fn part_two(records: &Self::Input) -> u64 {
+        // For part 2, brute force will not be fast enough.
+        // After analysing the input, there can be up to 75 damaged springs to allocate between
+
+        // Read here, as rayon's threads below do not see the runner's overrides
+        let copies = UNFOLD.get();
+        let unfolded_records = records.iter().map(|r| r.unfold(copies)).collect_vec();
+
+        // Statistics in preparation for part 2.
+        let mut max_unknown_damaged_count = 0;
+        let mut max_unknown_count = 0;
+        for r in unfolded_records {
+            let mut unknown_count = 0u64;
+            let mut damaged_count = 0u64;
+            for s in r.springs {
+                match s {
+                    Spring::Operational => {}
+                    Spring::Damaged => { damaged_count += 1; }
+                    Spring::Unknown => { unknown_count += 1; }
+                }
+            }
+            let total_damaged_count = r.groups.iter().sum::<u64>();
+            let unknown_damaged_count = total_damaged_count - damaged_count;
+            if unknown_damaged_count > max_unknown_damaged_count {
+                max_unknown_damaged_count = unknown_damaged_count;
+            }
+            if unknown_count > max_unknown_count {
+                max_unknown_count = unknown_count;
+                debug!("max_unknown_count={} with unknown_damaged={}", unknown_count, unknown_damaged_count);
             }
         }
-        let total_damaged_count = r.groups.iter().sum::<u64>();
-        let unknown_damaged_count = total_damaged_count - damaged_count;
-        if unknown_damaged_count > max_unknown_damaged_count {
-            max_unknown_damaged_count = unknown_damaged_count;
-        }
-        if unknown_count > max_unknown_count {
-            max_unknown_count = unknown_count;
-            println!("max_unknown_count={} with unknown_damaged={}", unknown_count, unknown_damaged_count);
-        }
-    }
 
-    println!("Max unknown damaged count: {}", max_unknown_damaged_count);
-    println!("Max unknown count: {}", max_unknown_count);
-
-    // In worst examples, we'd be brute-forcing 10e25 combinations.
-
-    // Potential recursion? - leads to smaller sub-problems
-    // ???.###????.###????.###????.###????.### 1,1,3,1,1,3,1,1,3,1,1,3,1,1,3
-    //  -> pick & trim
-    // #??.###????.###????.###????.###????.### 1,1,3,1,1,3,1,1,3,1,1,3,1,1,3 (1.1)
-    // .??.###????.###????.###????.###????.### 1,1,3,1,1,3,1,1,3,1,1,3,1,1,3 (1.2)
-
-    // From 1.1
-    // #.?.###????.###????.###????.###????.### _,1,3,1,1,3,1,1,3,1,1,3,1,1,3 (1.1.1)
-    // ##?.###????.###????.###????.###????.### _,1,3,1,1,3,1,1,3,1,1,3,1,1,3 IMPOSSIBLE
-    // Trimmed:
-    // ?.###????.###????.###????.###????.### 1,3,1,1,3,1,1,3,1,1,3,1,1,3 (1.1.1)
-    // From 1.1.1
-    // ..###????.###????.###????.###????.### 1,3,1,1,3,1,1,3,1,1,3,1,1,3 (1.1.1.1) - IMPOSSIBLE
-    // #.###????.###????.###????.###????.### 1,3,1,1,3,1,1,3,1,1,3,1,1,3 (1.1.1.2)
-    // Trimmed:
-    // ###????.###????.###????.###????.### 3,1,1,3,1,1,3,1,1,3,1,1,3 (1.1.1.2)
-    // ... here we'll also quickly loose one of the branches.
-    let combinations_sum = records.par_iter().map(|r| {
-        println!("...");
-        count_combinations_recursive(&r.unfold())
-    }).sum::<u64>();
-    println!("Combinations sum [part 2, recursive]: {}", combinations_sum);
+        debug!("Max unknown damaged count: {}", max_unknown_damaged_count);
+        debug!("Max unknown count: {}", max_unknown_count);
+
+        // In worst examples, we'd be brute-forcing 10e25 combinations.
+
+        // Potential recursion? - leads to smaller sub-problems
+        // ???.###????.###????.###????.###????.### 1,1,3,1,1,3,1,1,3,1,1,3,1,1,3
+        //  -> pick & trim
+        // #??.###????.###????.###????.###????.### 1,1,3,1,1,3,1,1,3,1,1,3,1,1,3 (1.1)
+        // .??.###????.###????.###????.###????.### 1,1,3,1,1,3,1,1,3,1,1,3,1,1,3 (1.2)
+
+        // From 1.1
+        // #.?.###????.###????.###????.###????.### _,1,3,1,1,3,1,1,3,1,1,3,1,1,3 (1.1.1)
+        // ##?.###????.###????.###????.###????.### _,1,3,1,1,3,1,1,3,1,1,3,1,1,3 IMPOSSIBLE
+        // Trimmed:
+        // ?.###????.###????.###????.###????.### 1,3,1,1,3,1,1,3,1,1,3,1,1,3 (1.1.1)
+        // From 1.1.1
+        // ..###????.###????.###????.###????.### 1,3,1,1,3,1,1,3,1,1,3,1,1,3 (1.1.1.1) - IMPOSSIBLE
+        // #.###????.###????.###????.###????.### 1,3,1,1,3,1,1,3,1,1,3,1,1,3 (1.1.1.2)
+        // Trimmed:
+        // ###????.###????.###????.###????.### 3,1,1,3,1,1,3,1,1,3,1,1,3 (1.1.1.2)
+        // ... here we'll also quickly loose one of the branches.
+        // Rayon's threads need the runner's token to be cancelled along with this one
+        let token = CancelToken::current();
+        records.par_iter().map(|r| {
+            trace!("Counting combinations of {:?}", r);
+            token.scope(|| count_combinations_recursive(&r.unfold(copies)))
+        }).sum::<u64>()
+    }
 }
 
 #[cfg(test)]
@@ -331,9 +345,9 @@ mod tests {
 
     #[test]
     fn test_trim() {
-        assert_eq!(parse_record(".??..??...?##. 1,1,3").trim(), Some(parse_record(".??..??...?##. 1,1,3")));
-        assert_eq!(parse_record("..?..??...?##. 1,1,3").trim(), Some(parse_record("..?..??...?##. 1,1,3")));
-        assert_eq!(parse_record(".#?..??...?##. 1,1,3").trim(), Some(parse_record(".#?..??...?##. 1,1,3")));
-        assert_eq!(parse_record(".#...??...?##. 1,1,3").trim(), Some(parse_record("..??...?##. 1,3")));
+        assert_eq!(parse_record(".??..??...?##. 1,1,3").unwrap().1.trim(), Some(parse_record(".??..??...?##. 1,1,3").unwrap().1));
+        assert_eq!(parse_record("..?..??...?##. 1,1,3").unwrap().1.trim(), Some(parse_record("..?..??...?##. 1,1,3").unwrap().1));
+        assert_eq!(parse_record(".#?..??...?##. 1,1,3").unwrap().1.trim(), Some(parse_record(".#?..??...?##. 1,1,3").unwrap().1));
+        assert_eq!(parse_record(".#...??...?##. 1,1,3").unwrap().1.trim(), Some(parse_record("..??...?##. 1,3").unwrap().1));
     }
 }
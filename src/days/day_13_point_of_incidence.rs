@@ -0,0 +1,75 @@
+//! Solution to an Advent of Code problem, day 13, 2023
+//! https://adventofcode.com/2023/day/13
+
+use log::trace;
+
+use crate::grid::Grid;
+use crate::params::{Param, ParamInfo};
+use crate::{ParseError, Solution};
+
+const SMUDGES_1: Param<usize> = Param::new("smudges_1", 0, "Cells that differ from their mirror image, in part 1");
+const SMUDGES_2: Param<usize> = Param::new("smudges_2", 1, "Cells that differ from their mirror image, in part 2");
+
+fn is_mirrored_x(pattern: &Grid<u8>, axis: usize, required_smudges: usize) -> bool {
+    trace!("is_mirrored_x({})", axis);
+    let w = pattern.width();
+    let mut smudge_count = 0usize;
+
+    for line in pattern.rows() {
+        for dx in 0..w {
+            if axis + dx < w && axis > dx {
+                let a = axis + dx;
+                let b = axis - dx - 1;
+                if line[a] != line[b] {
+                    trace!("is_mirrored_x({}): {} != {} (line[{}] != line[{}]", axis, line[a], line[b], a, b);
+                    smudge_count += 1;
+                }
+            }
+        }
+    }
+
+    smudge_count == required_smudges
+}
+
+fn find_axis(pattern: &Grid<u8>, required_smudges: usize) -> u64 {
+    // x
+    for x in 1..pattern.width() {
+        if is_mirrored_x(pattern, x, required_smudges) {
+            return x as u64;
+        }
+    }
+
+    // y, as x of the transposed pattern
+    let transposed = pattern.transpose();
+    for y in 1..transposed.width() {
+        if is_mirrored_x(&transposed, y, required_smudges) {
+            return 100u64 * y as u64;
+        }
+    }
+
+    0
+}
+
+pub struct PointOfIncidence;
+
+impl Solution for PointOfIncidence {
+    type Input = Vec<Grid<u8>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const PARAMS: &'static [&'static dyn ParamInfo] = &[&SMUDGES_1, &SMUDGES_2];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.trim_end().split("\n\n").map(|pattern| Grid::parse_part(input, pattern)).collect()
+    }
+
+    fn part_one(patterns: &Self::Input) -> u64 {
+        let smudges = SMUDGES_1.get();
+        patterns.iter().map(|p| find_axis(p, smudges)).sum::<u64>()
+    }
+
+    fn part_two(patterns: &Self::Input) -> u64 {
+        let smudges = SMUDGES_2.get();
+        patterns.iter().map(|p| find_axis(p, smudges)).sum::<u64>()
+    }
+}
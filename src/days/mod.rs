@@ -0,0 +1,13 @@
+pub mod day_01_trebuchet;
+pub mod day_02_cube_conundrum;
+pub mod day_03_gear_ratios;
+pub mod day_04_scratchcards;
+pub mod day_05_seed_fertilizer;
+pub mod day_06_wait_for_it;
+pub mod day_07_camel_cards;
+pub mod day_08_haunted_wasteland;
+pub mod day_09_mirage_maintenance;
+pub mod day_10_pipe_maze;
+pub mod day_11_cosmic_expansion;
+pub mod day_12_hot_springs;
+pub mod day_13_point_of_incidence;
@@ -0,0 +1,113 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use nom::error::ErrorKind;
+use nom::{Finish, IResult};
+
+use crate::cancel::checkpoint;
+
+/// Input that could not be parsed, with the position of the offending character.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, in characters
+    pub column: usize,
+    pub line_text: String,
+    pub kind: ErrorKind,
+}
+
+impl ParseError {
+    /// Locates `rest`, the unparsed remainder at the point of failure, within the whole `input`.
+    pub fn new(input: &str, rest: &str, kind: ErrorKind) -> ParseError {
+        let start = input.as_ptr() as usize;
+        let at = rest.as_ptr() as usize;
+        let offset = match at >= start && at <= start + input.len() {
+            true => at - start,
+            false => 0, // not a slice of input, nothing better to point at
+        };
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+
+        ParseError {
+            file: None,
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            line_text: input[line_start..line_end].trim_end_matches('\r').to_string(),
+            kind,
+        }
+    }
+
+    pub fn with_file(mut self, file: &Path) -> ParseError {
+        self.file = Some(file.to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file.as_ref().map_or("<input>".to_string(), |f| f.display().to_string());
+        let gutter = self.line.to_string().len();
+
+        writeln!(f, "Cannot parse {}:{}:{}: {}", file, self.line, self.column, self.kind.description())?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.line_text)?;
+        write!(f, "{:gutter$} | {:>column$}", "", "^", column = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` on `part`, a slice of the whole `input`, requiring it to consume all of `part`.
+pub fn parse_all<'a, O, F>(input: &'a str, part: &'a str, mut parser: F) -> Result<O, ParseError>
+    where F: FnMut(&'a str) -> IResult<&'a str, O> {
+    // Days parse their input a line or a block at a time with this, so it is where parsing can be cancelled
+    checkpoint();
+    match parser(part).finish() {
+        Ok(("", output)) => Ok(output),
+        Ok((rest, _)) => Err(ParseError::new(input, rest, ErrorKind::Eof)),
+        Err(e) => Err(ParseError::new(input, e.input, e.code)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::bytes::complete::tag;
+    use nom::character::complete::u64;
+    use nom::sequence::preceded;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_all_is_cancellable() {
+        let token = crate::cancel::CancelToken::new();
+        token.cancel();
+        let result = std::panic::catch_unwind(|| token.scope(|| parse_all("7", "7", u64)));
+        assert!(result.unwrap_err().is::<crate::cancel::Cancelled>());
+    }
+
+    #[test]
+    fn test_position() {
+        let input = "Card 1: 7\nCard 2: x\n";
+        let line = input.lines().nth(1).unwrap();
+        let e = parse_all(input, line, preceded(tag("Card 2: "), u64)).unwrap_err();
+        assert_eq!((e.line, e.column, e.kind), (2, 9, ErrorKind::Digit));
+        assert_eq!(e.line_text, "Card 2: x");
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        let input = "12 apples";
+        let e = parse_all(input, input, u64).unwrap_err();
+        assert_eq!((e.line, e.column, e.kind), (1, 3, ErrorKind::Eof));
+    }
+
+    #[test]
+    fn test_display() {
+        let input = "1\n22x\n";
+        let e = ParseError::new(input, &input[4..], ErrorKind::Digit).with_file(Path::new("in.txt"));
+        assert_eq!(e.to_string(), "Cannot parse in.txt:2:3: Digit\n  |\n2 | 22x\n  |   ^");
+    }
+}
@@ -0,0 +1,277 @@
+//! Examples from the puzzle text, extracted from a saved puzzle page.
+//!
+//! Each part of the puzzle is an `<article>`. Its example input is the first `<pre><code>` block, and the answer
+//! for it the last emphasized code of the article, like `<code><em>142</em></code>`: the text emphasizes values on
+//! the way to the answer as well, like the points of each card, but the answer comes last.
+//! A part 2 without an example of its own uses the one of part 1.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answers::Answers;
+use crate::registry::Part;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part: Part,
+    /// None if the part uses the example input of part 1
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Examples of the parts present on the page: part 1 only, until it is solved.
+pub fn extract(html: &str) -> Vec<Example> {
+    between_all(html, "<article", "</article>")
+        .into_iter()
+        .zip(Part::BOTH)
+        .map(|(article, part)| {
+            let answer = emphasized_code(article).pop().map(|(_, answer)| answer);
+            // A block that is never closed, as in a truncated save, counts as no example
+            let block = between_all(article, "<pre><code>", "</code></pre>").first()
+                .map(|block| decode(&strip_tags(block)));
+            match (block, part) {
+                (Some(block), _) => Example { part, input: Some(block), answer },
+                // Only part 2 can reuse an example; the answers of part 1 are not known without its own
+                (None, Part::One) => Example { part, input: None, answer: None },
+                (None, Part::Two) => Example { part, input: None, answer },
+            }
+        })
+        .collect()
+}
+
+/// Everything between each `start` and the following `end`, where `start` may be an opening tag with attributes.
+fn between_all<'a>(text: &'a str, start: &str, end: &str) -> Vec<&'a str> {
+    let mut found = vec![];
+    let mut rest = text;
+    while let Some(at) = rest.find(start) {
+        let after_start = &rest[at + start.len()..];
+        // Skip the remainder of an opening tag like `<article class="day-desc">`
+        let content_start = match start.ends_with('>') {
+            true => 0,
+            false => after_start.find('>').map_or(after_start.len(), |i| i + 1),
+        };
+        let content = &after_start[content_start..];
+        match content.find(end) {
+            Some(len) => {
+                found.push(&content[..len]);
+                rest = &content[len + end.len()..];
+            }
+            None => break,
+        }
+    }
+    found
+}
+
+/// Text of `<code><em>…</em></code>` and `<em><code>…</code></em>`, with their offsets.
+fn emphasized_code(article: &str) -> Vec<(usize, String)> {
+    let mut found = vec![];
+    for (open, close) in [("<code><em>", "</em></code>"), ("<em><code>", "</code></em>")] {
+        let mut offset = 0;
+        while let Some(at) = article[offset..].find(open) {
+            let start = offset + at + open.len();
+            let Some(len) = article[start..].find(close) else { break };
+            found.push((offset + at, decode(&strip_tags(&article[start..start + len]))));
+            offset = start + len;
+        }
+    }
+    found.sort();
+    found
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Where the example of part 2 goes when it differs from the one of part 1, e.g.
+/// `test_inputs/day_08_haunted_wasteland_part_2.txt` next to `test_inputs/day_08_haunted_wasteland.txt`.
+pub fn part_two_path(path: &Path) -> PathBuf {
+    let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+    path.with_file_name(format!("{}_part_2.txt", stem))
+}
+
+/// The example files to solve `parts` with, each with the parts it is for: the example at `path`,
+/// except for part 2 if it has one of its own at `part_two_path`.
+pub fn examples_by_part(path: &Path, parts: &[Part]) -> Vec<(PathBuf, Vec<Part>)> {
+    let part_two = part_two_path(path);
+    match parts.contains(&Part::Two) && part_two.exists() {
+        true => {
+            let shared: Vec<Part> = parts.iter().copied().filter(|&part| part != Part::Two).collect();
+            let mut examples = vec![];
+            if !shared.is_empty() {
+                examples.push((path.to_path_buf(), shared));
+            }
+            examples.push((part_two, vec![Part::Two]));
+            examples
+        }
+        false => vec![(path.to_path_buf(), parts.to_vec())],
+    }
+}
+
+/// Writes example inputs and records their answers. The example of part 1 goes to `path`,
+/// one that only part 2 uses to its `part_two_path`. Returns what was written, for the user to check.
+///
+/// Existing files with other contents are kept unless `overwrite` is set.
+pub fn save(examples: &[Example], path: &Path, answers: &mut Answers, overwrite: bool) -> Result<Vec<String>, String> {
+    let mut written = vec![];
+    for example in examples {
+        let target = match (example.part, &example.input) {
+            (Part::Two, Some(_)) => part_two_path(path),
+            _ => path.to_path_buf(),
+        };
+        let target = target.as_path();
+
+        if let Some(input) = &example.input {
+            match fs::read_to_string(target) {
+                Ok(existing) if existing == *input => {}
+                Ok(existing) if !existing.is_empty() && !overwrite => {
+                    return Err(format!("{} exists with other contents, overwriting is not allowed", target.display()));
+                }
+                _ => {
+                    fs::write(target, input).map_err(|e| format!("Cannot write {}: {}", target.display(), e))?;
+                    written.push(format!("{} ({} lines)", target.display(), input.lines().count()));
+                }
+            }
+        }
+
+        if let Some(answer) = &example.answer {
+            answers.record(&target.display().to_string(), example.part, answer);
+            written.push(format!("{} [part {}]: {}", target.display(), example.part, answer));
+        }
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>In this example, the values are <code>12</code> and <code>38</code>. Adding these together produces <code><em>50</em></code>.</p>
+<p><em>What is the sum of all of the calibration values?</em></p>
+</article>
+<p>Your puzzle answer was <code>56465</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Equipped with this new information, you now need to find the real first and last digit on each line. For example:</p>
+<pre><code>two1nine
+<em>eight</em>wo&lt;three
+</code></pre>
+<p>Adding these together produces <em><code>281</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(extract(PAGE), vec![
+            Example { part: Part::One, input: Some("1abc2\npqr3stu8vwx\n".to_string()), answer: Some("50".to_string()) },
+            Example { part: Part::Two, input: Some("two1nine\neightwo<three\n".to_string()), answer: Some("281".to_string()) },
+        ]);
+    }
+
+    #[test]
+    fn test_extract_part_two_reusing_example() {
+        let page = r#"<article><pre><code>1 2
+</code></pre><p>Total <code><em>3</em></code>.</p></article>
+<article><p>With <code><em>12</em></code> instead, the total is <code><em>24</em></code>.</p></article>"#;
+        assert_eq!(extract(page), vec![
+            Example { part: Part::One, input: Some("1 2\n".to_string()), answer: Some("3".to_string()) },
+            Example { part: Part::Two, input: None, answer: Some("24".to_string()) },
+        ]);
+    }
+
+    #[test]
+    fn test_extract_after_intermediate_values() {
+        // Day 4: the points of each card are emphasized before the total
+        let page = r#"<article class="day-desc"><h2>--- Day 4: Scratchcards ---</h2>
+<pre><code>Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+</code></pre>
+<p>In the above example, card 1 has five winning numbers (<code>41</code>, <code>48</code>, <code>83</code>, <code>86</code>, and <code>17</code>) and eight numbers you have (<code>83</code>, <code>86</code>, <code>6</code>, <code>31</code>, <code>17</code>, <code>9</code>, <code>48</code>, and <code>53</code>). Of the numbers you have, four of them (<code>48</code>, <code>83</code>, <code>17</code>, and <code>86</code>) are <em>winning numbers</em>! That means card 1 is worth <code><em>8</em></code> points (1 for the first match, then doubled three times for each of the three matches after the first).</p>
+<ul>
+<li>Card 2 has two winning numbers (<code>32</code> and <code>61</code>), so it is worth <code><em>2</em></code> points.</li>
+</ul>
+<p>So, in this example, the Elf's pile of scratchcards is worth <code><em>13</em></code> points.</p>
+<p>Take a seat in the large pile of colorful cards. <em>How many points are they worth in total?</em></p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Once all of the originals and copies have been processed, you end up with <code><em>1</em></code> instance of card 1, <code><em>2</em></code> instances of card 2, and so on. In total, this example pile of scratchcards causes you to ultimately have <code><em>30</em></code> scratchcards!</p>
+<p>Process all of the original and copied scratchcards until no more scratchcards are won. Including the original set of scratchcards, <em>how many total scratchcards do you end up with?</em></p>
+</article>"#;
+        let answers: Vec<Option<String>> = extract(page).into_iter().map(|example| example.answer).collect();
+        assert_eq!(answers, [Some("13".to_string()), Some("30".to_string())]);
+
+        // Day 6: the ways to win each race come first, and the product is followed by plain code
+        let page = r#"<article class="day-desc"><h2>--- Day 6: Wait For It ---</h2>
+<pre><code>Time:      7  15   30
+Distance:  9  40  200
+</code></pre>
+<p>Since the current record for this race is <code>9</code> millimeters, there are actually <code><em>4</em></code> different ways you could win: you could hold the button for <code>2</code>, <code>3</code>, <code>4</code>, or <code>5</code> milliseconds at the start of the race.</p>
+<p>In this example, if you multiply these values together, you get <code><em>288</em></code> (<code>4</code> * <code>8</code> * <code>9</code>).</p>
+</article>"#;
+        assert_eq!(extract(page)[0].answer.as_deref(), Some("288"));
+    }
+
+    #[test]
+    fn test_extract_unclosed_example() {
+        let page = "<article><p>Total <code><em>3</em></code>.</p><pre><code>1 2\n</article>";
+        assert_eq!(extract(page), vec![Example { part: Part::One, input: None, answer: None }]);
+    }
+
+    #[test]
+    fn test_extract_without_articles() {
+        assert_eq!(extract("<html>Please log in</html>"), vec![]);
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = std::env::temp_dir().join(format!("aoc_examples_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("day_01_trebuchet.txt");
+        fs::write(&path, "").unwrap();
+        let mut answers = Answers::default();
+
+        save(&extract(PAGE), &path, &mut answers, false).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1abc2\npqr3stu8vwx\n");
+        assert_eq!(fs::read_to_string(dir.join("day_01_trebuchet_part_2.txt")).unwrap(), "two1nine\neightwo<three\n");
+        assert_eq!(answers.get(&path.display().to_string(), Part::One), Some("50"));
+        assert_eq!(answers.get(&part_two_path(&path).display().to_string(), Part::Two), Some("281"));
+
+        // Saving the same examples again changes nothing, other ones are refused
+        assert!(save(&extract(PAGE), &path, &mut answers, false).unwrap().iter().all(|w| !w.contains("lines")));
+        fs::write(&path, "edited\n").unwrap();
+        assert!(save(&extract(PAGE), &path, &mut answers, false).is_err());
+        assert!(save(&extract(PAGE), &path, &mut answers, true).is_ok());
+
+        assert_eq!(examples_by_part(&path, &Part::BOTH), [
+            (path.clone(), vec![Part::One]),
+            (part_two_path(&path), vec![Part::Two]),
+        ]);
+        assert_eq!(examples_by_part(&path, &[Part::Two]), [(part_two_path(&path), vec![Part::Two])]);
+        fs::remove_file(part_two_path(&path)).unwrap();
+        assert_eq!(examples_by_part(&path, &Part::BOTH), [(path.clone(), Part::BOTH.to_vec())]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,255 @@
+//! Rectangular grids of cells addressed by `(x, y)`, with `(0, 0)` in the top-left corner.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use nom::error::ErrorKind;
+
+use crate::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Row after row
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Returns `None` if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, |r| r.len());
+        if rows.iter().any(|r| r.len() != width) {
+            return None;
+        }
+        let height = rows.len();
+        Some(Grid { width, height, cells: rows.into_iter().flatten().collect() })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        match x < self.width && y < self.height {
+            true => Some(&self.cells[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        match x < self.width && y < self.height {
+            true => Some(&mut self.cells[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    /// All coordinates, row by row.
+    pub fn positions(&self) -> impl Iterator<Item=(usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their coordinates, row by row.
+    pub fn iter(&self) -> impl Iterator<Item=((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Orthogonal neighbours that lie inside the grid: up, right, down, left.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item=(usize, usize)> {
+        self.offsets(x, y, &[(0, -1), (1, 0), (0, 1), (-1, 0)])
+    }
+
+    /// Orthogonal and diagonal neighbours that lie inside the grid, clockwise from the top-left one.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item=(usize, usize)> {
+        self.offsets(x, y, &[(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)])
+    }
+
+    fn offsets(&self, x: usize, y: usize, deltas: &'static [(isize, isize)]) -> impl Iterator<Item=(usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        deltas.iter().filter_map(move |&(dx, dy)| {
+            let nx = x.checked_add_signed(dx).filter(|&nx| nx < width)?;
+            let ny = y.checked_add_signed(dy).filter(|&ny| ny < height)?;
+            Some((nx, ny))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item=&T> {
+        assert!(x < self.width, "Column {} out of a grid {} wide", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item=impl Iterator<Item=&T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Grid of the given size whose cell `(x, y)` is `f(x, y)`.
+    fn build(width: usize, height: usize, f: impl FnMut((usize, usize)) -> T) -> Grid<T> {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(f).collect();
+        Grid { width, height, cells }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::build(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        Grid::build(self.height, self.width, |(x, y)| self[(y, self.height - 1 - x)].clone())
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        Grid::build(self.height, self.width, |(x, y)| self[(self.width - 1 - y, x)].clone())
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::build(self.width, self.height, |(x, y)| self[(self.width - 1 - x, y)].clone())
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::build(self.width, self.height, |(x, y)| self[(x, self.height - 1 - y)].clone())
+    }
+}
+
+impl Grid<u8> {
+    /// Grid of the bytes of each line. All lines must have the same length.
+    pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse_part(input, input)
+    }
+
+    /// Like [`Grid::parse`] for `part`, a slice of the whole `input`, so errors point into `input`.
+    pub fn parse_part(input: &str, part: &str) -> Result<Grid<u8>, ParseError> {
+        let lines: Vec<&str> = part.lines().collect();
+        let width = lines.first().map_or(0, |l| l.len());
+        if let Some(line) = lines.iter().find(|l| l.len() != width) {
+            // Past the width of the first line, or at the end of a shorter one; inside a character, at its start
+            let cut = (0..=width.min(line.len())).rev().find(|&i| line.is_char_boundary(i)).unwrap_or(0);
+            let at = &line[cut..];
+            return Err(ParseError::new(input, at, ErrorKind::Verify));
+        }
+        Ok(Grid::from_rows(lines.iter().map(|l| l.as_bytes().to_vec()).collect()).unwrap())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| panic!("({}, {}) out of a {}x{} grid", x, y, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y).unwrap_or_else(|| panic!("({}, {}) out of a {}x{} grid", x, y, width, height))
+    }
+}
+
+/// Rows of characters, one per line.
+impl fmt::Display for Grid<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        Grid::parse("abc\ndef\n").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let g = grid();
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[(2, 1)], b'f');
+        assert_eq!(g.get(3, 0), None);
+        assert_eq!(g.get(0, 2), None);
+    }
+
+    #[test]
+    fn test_parse_rejects_ragged_rows_of_multibyte_characters() {
+        let e = Grid::parse("abcd\naéfgh").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+        // Width 2 ends inside the é
+        let e = Grid::parse("ab\naéfgh").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+    }
+
+    #[test]
+    fn test_parse_rejects_ragged_rows() {
+        let e = Grid::parse("abc\nde\nfgh").unwrap_err();
+        assert_eq!((e.line, e.column, e.kind), (2, 3, ErrorKind::Verify));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = grid();
+        assert_eq!(g.neighbours4(0, 0).collect_vec(), vec![(1, 0), (0, 1)]);
+        assert_eq!(g.neighbours4(1, 1).collect_vec(), vec![(1, 0), (2, 1), (0, 1)]);
+        assert_eq!(g.neighbours8(2, 0).collect_vec(), vec![(2, 1), (1, 1), (1, 0)]);
+        assert_eq!(g.neighbours8(1, 0).count(), 5);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let g = grid();
+        assert_eq!(g.row(1), b"def");
+        assert_eq!(g.column(2).copied().collect_vec(), b"cf");
+        assert_eq!(g.columns().count(), 3);
+        assert_eq!(g.rows().count(), 2);
+    }
+
+    #[test]
+    fn test_transformations() {
+        let g = grid();
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(g.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(g.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(g.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(g.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(g.rotate_clockwise().rotate_counterclockwise(), g);
+    }
+
+    #[test]
+    fn test_positions() {
+        let g = grid();
+        assert_eq!(g.iter().find(|(_, &c)| c == b'e').map(|(p, _)| p), Some((1, 1)));
+        assert_eq!(g.positions().count(), 6);
+    }
+}
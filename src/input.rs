@@ -0,0 +1,111 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::registry::{find_day, Day};
+
+/// Which of the two inputs every day has: the personal puzzle input or the example from the puzzle text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Real,
+    Example,
+}
+
+impl InputKind {
+    pub fn directory(self) -> &'static str {
+        match self {
+            InputKind::Real => "inputs",
+            InputKind::Example => "test_inputs",
+        }
+    }
+
+    pub fn path(self, day: &Day) -> PathBuf {
+        PathBuf::from(self.directory()).join(format!("{}.txt", day.name()))
+    }
+
+    /// Path of the input of a day given by number. Days not registered yet are found by their
+    /// input file, if one was created already.
+    pub fn find_path(self, number: u8) -> Option<PathBuf> {
+        if let Some(day) = find_day(number) {
+            return Some(self.path(day));
+        }
+
+        let prefix = format!("day_{:02}_", number);
+        fs::read_dir(self.directory()).ok()?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .find(|path| path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".txt")))
+    }
+}
+
+/// Input path that stands for the standard input.
+pub const STDIN: &str = "-";
+
+/// Reads an input file, or the standard input for `-`.
+pub fn read_input(path: &Path) -> io::Result<String> {
+    match path == Path::new(STDIN) {
+        true => {
+            let mut contents = String::new();
+            io::stdin().read_to_string(&mut contents)?;
+            Ok(contents)
+        }
+        false => fs::read_to_string(path),
+    }
+}
+
+/// Inputs given on the command line: `-` and files as they are, directories as the `.txt` files in them,
+/// sorted by name so results come in the same order every time.
+pub fn expand_inputs(paths: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+    let mut inputs = vec![];
+    for path in paths {
+        match path.is_dir() {
+            true => {
+                let entries = fs::read_dir(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+                let mut files = entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter(|file| file.is_file() && file.extension().is_some_and(|extension| extension == "txt"))
+                    .collect::<Vec<_>>();
+                if files.is_empty() {
+                    return Err(format!("No .txt files in {}", path.display()));
+                }
+                files.sort();
+                inputs.extend(files);
+            }
+            false => inputs.push(path.clone()),
+        }
+    }
+
+    if inputs.iter().filter(|path| *path == Path::new(STDIN)).count() > 1 {
+        return Err("The standard input can be read only once".to_string());
+    }
+    Ok(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc_inputs_{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        for name in ["bob.txt", "alice.txt", "notes.md"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let expanded = expand_inputs(&[PathBuf::from("-"), dir.clone(), PathBuf::from("inputs/day_07_camel_cards.txt")]);
+        assert_eq!(expanded, Ok(vec![
+            PathBuf::from("-"),
+            dir.join("alice.txt"),
+            dir.join("bob.txt"),
+            PathBuf::from("inputs/day_07_camel_cards.txt"),
+        ]));
+
+        assert!(expand_inputs(&[dir.join("nested")]).is_err());
+        assert!(expand_inputs(&[PathBuf::from("-"), PathBuf::from("-")]).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
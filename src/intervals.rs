@@ -0,0 +1,316 @@
+//! Half-open integer intervals, sets of them, and maps that shift parts of the number line.
+
+use std::fmt;
+
+/// Numbers from `start` up to, but not including, `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Interval {
+        assert!(start <= end, "Interval start {} is after its end {}", start, end);
+        Interval { start, end }
+    }
+
+    pub fn with_length(start: i64, length: i64) -> Interval {
+        Interval::new(start, start + length)
+    }
+
+    pub fn len(&self) -> i64 {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        match start < end {
+            true => Some(Interval { start, end }),
+            false => None,
+        }
+    }
+
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval { start: self.start + offset, end: self.end + offset }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// Set of numbers kept as sorted, disjoint intervals. Touching intervals are coalesced into one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // Intervals overlapping or touching the new one are merged into it
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let mut merged = interval;
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = IntervalSet::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            if let Some(common) = a.intersection(&b) {
+                result.intervals.push(common);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        result
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = IntervalSet::new();
+        for &interval in &self.intervals {
+            let mut start = interval.start;
+            for removed in other.intervals.iter().filter(|r| r.intersection(&interval).is_some()) {
+                if removed.start > start {
+                    result.intervals.push(Interval::new(start, removed.start));
+                }
+                start = start.max(removed.end);
+            }
+            if start < interval.end {
+                result.intervals.push(Interval::new(start, interval.end));
+            }
+        }
+        result
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= value);
+        i < self.intervals.len() && self.intervals[i].contains(value)
+    }
+
+    /// Count of numbers in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(|i| i.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=&Interval> {
+        self.intervals.iter()
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item=Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+/// Two pieces of an [`OffsetMap`] claiming the same numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverlapError {
+    pub first: Interval,
+    pub second: Interval,
+}
+
+impl fmt::Display for OverlapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Intervals {} and {} overlap", self.first, self.second)
+    }
+}
+
+impl std::error::Error for OverlapError {}
+
+/// Piecewise map that shifts numbers in each of its intervals by that interval's offset.
+/// Numbers outside all intervals map to themselves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OffsetMap {
+    /// Sorted by start, non-overlapping
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl OffsetMap {
+    pub fn new(mut pieces: Vec<(Interval, i64)>) -> Result<OffsetMap, OverlapError> {
+        pieces.retain(|(interval, _)| !interval.is_empty());
+        pieces.sort_by_key(|(interval, _)| interval.start);
+        for w in pieces.windows(2) {
+            if w[0].0.end > w[1].0.start {
+                return Err(OverlapError { first: w[0].0, second: w[1].0 });
+            }
+        }
+        Ok(OffsetMap { pieces })
+    }
+
+    pub fn map_value(&self, value: i64) -> i64 {
+        let i = self.pieces.partition_point(|(interval, _)| interval.end <= value);
+        match self.pieces.get(i) {
+            Some((interval, offset)) if interval.contains(value) => value + offset,
+            _ => value,
+        }
+    }
+
+    /// Image of the interval, one piece per part of the map it crosses, in order of the source numbers.
+    pub fn map_interval(&self, interval: Interval) -> Vec<Interval> {
+        let mut output = vec![];
+        let mut start = interval.start;
+
+        for &(piece, offset) in &self.pieces {
+            if start >= interval.end {
+                break;
+            }
+            if piece.end <= start {
+                continue;
+            }
+
+            // Left of the piece
+            if piece.start > start {
+                let end = interval.end.min(piece.start);
+                output.push(Interval::new(start, end));
+                start = end;
+            }
+
+            // Inside the piece
+            if let Some(common) = Interval::new(start, interval.end.max(start)).intersection(&piece) {
+                output.push(common.shift(offset));
+                start = common.end;
+            }
+        }
+
+        // Right of all pieces
+        if start < interval.end {
+            output.push(Interval::new(start, interval.end));
+        }
+
+        output
+    }
+
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        set.iter().flat_map(|&i| self.map_interval(i)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals.iter().map(|&(start, end)| Interval::new(start, end)).collect()
+    }
+
+    #[test]
+    fn test_interval_intersection() {
+        assert_eq!(Interval::new(0, 10).intersection(&Interval::new(5, 15)), Some(Interval::new(5, 10)));
+        assert_eq!(Interval::new(0, 10).intersection(&Interval::new(10, 15)), None);
+    }
+
+    #[test]
+    fn test_insert_coalesces() {
+        let s = set(&[(0, 3), (10, 12), (3, 5), (20, 30), (11, 21)]);
+        assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![Interval::new(0, 5), Interval::new(10, 30)]);
+        assert_eq!(s.len(), 25);
+        assert_eq!(s.min(), Some(0));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 30)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20)]));
+        assert_eq!(a.difference(&a), IntervalSet::new());
+    }
+
+    #[test]
+    fn test_contains() {
+        let s = set(&[(0, 10), (20, 30)]);
+        assert!(s.contains(0));
+        assert!(!s.contains(10));
+        assert!(s.contains(29));
+        assert!(!s.contains(-1));
+    }
+
+    #[test]
+    fn test_offset_map_rejects_overlap() {
+        let overlapping = vec![(Interval::new(10, 20), 1), (Interval::new(0, 11), 2)];
+        assert_eq!(OffsetMap::new(overlapping), Err(OverlapError {
+            first: Interval::new(0, 11),
+            second: Interval::new(10, 20),
+        }));
+    }
+
+    #[test]
+    fn test_map_value() {
+        let map = OffsetMap::new(vec![(Interval::new(98, 100), -48), (Interval::new(50, 98), 2)]).unwrap();
+        assert_eq!(map.map_value(79), 81);
+        assert_eq!(map.map_value(14), 14);
+        assert_eq!(map.map_value(99), 51);
+        assert_eq!(map.map_value(100), 100);
+    }
+
+    #[test]
+    fn test_map_interval() {
+        let map = OffsetMap::new(vec![(Interval::with_length(0, 10), 1), (Interval::with_length(401, 20), 2)]).unwrap();
+        assert_eq!(map.map_interval(Interval::with_length(100, 300)), vec![Interval::with_length(100, 300)]);
+        assert_eq!(map.map_interval(Interval::with_length(0, 3)), vec![Interval::with_length(1, 3)]);
+        assert_eq!(map.map_interval(Interval::with_length(500, 3)), vec![Interval::with_length(500, 3)]);
+        assert_eq!(map.map_interval(Interval::with_length(0, 500)), vec![
+            Interval::with_length(1, 10),
+            Interval::with_length(10, 401 - 10),
+            Interval::with_length(403, 20),
+            Interval::with_length(421, 500 - 421),
+        ]);
+
+        let map = OffsetMap::new(vec![
+            (Interval::with_length(0, 7), 42),
+            (Interval::with_length(7, 4), 50),
+            (Interval::with_length(11, 42), -11),
+            (Interval::with_length(53, 8), -4),
+        ]).unwrap();
+        assert_eq!(map.map_interval(Interval::with_length(57, 13)), vec![Interval::with_length(53, 4), Interval::with_length(61, 9)]);
+    }
+}
@@ -0,0 +1,30 @@
+//! Solutions of Advent of Code 2023
+//! https://adventofcode.com/2023
+//!
+//! Every day lives in its own module under [`days`] and implements the [`Solution`] trait,
+//! so solvers can be called from binaries, tests and other tools alike.
+
+#![feature(iter_array_chunks)]
+
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod client;
+pub mod cycles;
+pub mod days;
+pub mod examples;
+mod error;
+pub mod grid;
+pub mod input;
+pub mod intervals;
+pub mod math;
+pub mod params;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+mod solution;
+pub mod submit;
+pub mod watch;
+
+pub use error::{parse_all, ParseError};
+pub use solution::Solution;
@@ -0,0 +1,206 @@
+//! Number theory helpers: greatest common divisor, least common multiple,
+//! Chinese remainder theorem and integer square root.
+//!
+//! The `u64` functions panic on overflow, the `u128` variants report it instead.
+
+use std::fmt;
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    gcd_u128(a.into(), b.into()) as u64
+}
+
+pub fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Greatest common divisor of all numbers; 0 for none.
+pub fn gcd_all(numbers: &[u64]) -> u64 {
+    numbers.iter().fold(0, |acc, &n| gcd(acc, n))
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm_u128(a.into(), b.into())
+        .and_then(|l| l.try_into().ok())
+        .unwrap_or_else(|| panic!("lcm({}, {}) overflows u64", a, b))
+}
+
+/// Least common multiple of all numbers; 1 for none.
+pub fn lcm_all(numbers: &[u64]) -> u64 {
+    numbers.iter().fold(1, |acc, &n| lcm(acc, n))
+}
+
+/// Least common multiple, or `None` if it does not fit in `u128`.
+pub fn checked_lcm_u128(a: u128, b: u128) -> Option<u128> {
+    match (a, b) {
+        (0, _) | (_, 0) => Some(0),
+        _ => (a / gcd_u128(a, b)).checked_mul(b),
+    }
+}
+
+pub fn checked_lcm_all_u128(numbers: &[u128]) -> Option<u128> {
+    numbers.iter().try_fold(1, |acc, &n| checked_lcm_u128(acc, n))
+}
+
+/// `a * b % m` without overflowing, by doubling and adding.
+fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    let (mut a, mut b) = (a % m, b % m);
+    let mut result = 0u128;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    result
+}
+
+/// `(a + b) % m` for `a, b < m`, without overflowing.
+fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    match a >= m - b {
+        true => a - (m - b),
+        false => a + b,
+    }
+}
+
+/// `(a - b) mod m` for `a, b < m`.
+fn sub_mod(a: u128, b: u128, m: u128) -> u128 {
+    match a >= b {
+        true => a - b,
+        false => m - (b - a),
+    }
+}
+
+/// Inverse of `a` modulo `m`, for `a` coprime to `m`.
+fn inverse_mod(a: u128, m: u128) -> u128 {
+    // Extended Euclid, with the Bézout coefficient of `a` kept modulo `m`
+    let (mut r, mut new_r) = (m, a % m);
+    let (mut t, mut new_t) = (0u128, 1u128 % m);
+    while new_r != 0 {
+        let q = r / new_r;
+        (r, new_r) = (new_r, r - q * new_r);
+        (t, new_t) = (new_t, sub_mod(t, mul_mod(q, new_t, m), m));
+    }
+    t
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// The congruences contradict each other.
+    NoSolution,
+    /// The combined modulus does not fit in `u128`.
+    Overflow,
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::NoSolution => write!(f, "Congruences have no common solution"),
+            CrtError::Overflow => write!(f, "Combined modulus overflows u128"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Solves `x ≡ residue (mod modulus)` for all `(residue, modulus)` pairs at once.
+/// Moduli need not be coprime. Returns the smallest solution and the least common multiple
+/// of the moduli, which together describe all solutions.
+pub fn crt(congruences: &[(u128, u128)]) -> Result<(u128, u128), CrtError> {
+    let mut x = 0u128;
+    let mut m1 = 1u128;
+    for &(r2, m2) in congruences {
+        assert!(m2 > 0, "Modulus must be positive");
+        let r2 = r2 % m2;
+        let g = gcd_u128(m1, m2);
+        let diff = sub_mod(r2, x % m2, m2);
+        if !diff.is_multiple_of(g) {
+            return Err(CrtError::NoSolution);
+        }
+
+        // x + m1 * k ≡ r2 (mod m2), so k ≡ diff / g * (m1 / g)^-1 (mod m2 / g)
+        let m = m2 / g;
+        let k = mul_mod(diff / g, inverse_mod(m1 / g % m, m), m);
+        let l = (m1 / g).checked_mul(m2).ok_or(CrtError::Overflow)?;
+        x += m1 * k; // below m1 * m = l
+        m1 = l;
+    }
+    Ok((x, m1))
+}
+
+/// Largest number whose square is at most `n`.
+pub fn isqrt(n: u64) -> u64 {
+    isqrt_u128(n.into()) as u64
+}
+
+pub fn isqrt_u128(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method from above: the first guess is a power of two at least sqrt(n)
+    let mut x = 1u128 << ((128 - n.leading_zeros()).div_ceil(2));
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd_all(&[293 * 61, 293 * 67, 293 * 43]), 293);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm_all(&[2, 3, 4, 5]), 60);
+        assert_eq!(lcm_all(&[]), 1);
+    }
+
+    #[test]
+    fn test_checked_lcm() {
+        assert_eq!(checked_lcm_u128(u64::MAX as u128, 3), Some(u64::MAX as u128));
+        assert_eq!(checked_lcm_u128(u128::MAX, u128::MAX - 1), None);
+        assert_eq!(checked_lcm_all_u128(&[1 << 100, 3, 1 << 20]), Some(3 << 100));
+        assert_eq!(checked_lcm_all_u128(&[1 << 120, 257]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64")]
+    fn test_lcm_overflow() {
+        lcm(u64::MAX, u64::MAX - 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        // Moduli sharing factors
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Ok((7, 12)));
+        assert_eq!(crt(&[(0, 4), (1, 6)]), Err(CrtError::NoSolution));
+        assert_eq!(crt(&[]), Ok((0, 1)));
+        assert_eq!(crt(&[(1, u128::MAX), (0, 2)]), Err(CrtError::Overflow));
+
+        let big = (1u128 << 61) - 1; // prime
+        let (x, m) = crt(&[(5, big), (7, big - 2)]).unwrap();
+        assert_eq!((x % big, x % (big - 2), m), (5, 7, big * (big - 2)));
+    }
+
+    #[test]
+    fn test_isqrt() {
+        for n in 0..1000u64 {
+            let r = isqrt(n);
+            assert!(r * r <= n && (r + 1) * (r + 1) > n, "isqrt({}) = {}", n, r);
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt_u128(u128::MAX), u64::MAX as u128);
+    }
+}
@@ -0,0 +1,151 @@
+//! Named parameters of days, like the expansion factor of day 11, to try variants of a puzzle without editing code.
+//!
+//! A day declares its parameters with their defaults in [`Solution::PARAMS`](crate::Solution::PARAMS) and reads them
+//! with [`Param::get`]. The runner installs overrides given as `name=value` for the thread solving the day,
+//! after checking them against the declared parameters.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::panic::RefUnwindSafe;
+use std::str::FromStr;
+
+/// A parameter without its type, for listing and checking parameters of any day.
+pub trait ParamInfo: Sync + RefUnwindSafe {
+    fn name(&self) -> &'static str;
+    fn help(&self) -> &'static str;
+    fn default_value(&self) -> String;
+    /// Whether `value` can be read as a value of the parameter.
+    fn check(&self, value: &str) -> Result<(), String>;
+}
+
+pub struct Param<T> {
+    pub name: &'static str,
+    pub default: T,
+    pub help: &'static str,
+}
+
+impl<T> Param<T> {
+    pub const fn new(name: &'static str, default: T, help: &'static str) -> Param<T> {
+        Param { name, default, help }
+    }
+}
+
+impl<T: FromStr + Clone> Param<T> {
+    /// The value overridden for the current thread, or the default.
+    pub fn get(&self) -> T {
+        let value = CURRENT.with(|current| current.borrow().as_ref().and_then(|o| o.0.get(self.name).cloned()));
+        match value {
+            Some(value) => value.parse().unwrap_or_else(|_| panic!("Invalid value of {}: {}", self.name, value)),
+            None => self.default.clone(),
+        }
+    }
+}
+
+impl<T: FromStr<Err: Display> + Display + Sync + RefUnwindSafe> ParamInfo for Param<T> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn help(&self) -> &'static str {
+        self.help
+    }
+
+    fn default_value(&self) -> String {
+        self.default.to_string()
+    }
+
+    fn check(&self, value: &str) -> Result<(), String> {
+        value.parse::<T>().map(|_| ()).map_err(|e| format!("Invalid value of {}: {} ({})", self.name, value, e))
+    }
+}
+
+/// Values of parameters by name, in place of their defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Overrides(BTreeMap<String, String>);
+
+thread_local! {
+    static CURRENT: RefCell<Option<Overrides>> = const { RefCell::new(None) };
+}
+
+impl Overrides {
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.0.insert(name.to_string(), value.to_string());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Checks that every override names one of `params` and has a value of its type.
+    pub fn check(&self, params: &[&dyn ParamInfo]) -> Result<(), String> {
+        for (name, value) in &self.0 {
+            match params.iter().find(|p| p.name() == name) {
+                Some(param) => param.check(value)?,
+                None => return Err(format!("Unknown parameter: {}", name)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs `f` with these overrides installed for the current thread, restoring the previous ones afterwards,
+    /// also when `f` unwinds.
+    pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<Overrides>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+            }
+        }
+
+        let _restore = Restore(CURRENT.with(|current| current.borrow_mut().replace(self.clone())));
+        f()
+    }
+}
+
+/// Reads an override like `expansion_2=10`.
+pub fn parse_override(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.trim().to_string(), value.trim().to_string())),
+        _ => Err(format!("expected name=value, got {}", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FACTOR: Param<u64> = Param::new("factor", 5, "How many times");
+    const PARAMS: &[&dyn ParamInfo] = &[&FACTOR];
+
+    #[test]
+    fn test_get() {
+        assert_eq!(FACTOR.get(), 5);
+
+        let mut overrides = Overrides::default();
+        overrides.set("factor", "7");
+        assert_eq!(overrides.scope(|| FACTOR.get()), 7);
+        assert_eq!(FACTOR.get(), 5);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut overrides = Overrides::default();
+        overrides.set("factor", "7");
+        assert_eq!(overrides.check(PARAMS), Ok(()));
+
+        overrides.set("factor", "-1");
+        assert_eq!(overrides.check(PARAMS), Err("Invalid value of factor: -1 (invalid digit found in string)".to_string()));
+
+        let mut unknown = Overrides::default();
+        unknown.set("expansion", "10");
+        assert_eq!(unknown.check(PARAMS), Err("Unknown parameter: expansion".to_string()));
+    }
+
+    #[test]
+    fn test_parse_override() {
+        assert_eq!(parse_override("expansion_2=10"), Ok(("expansion_2".to_string(), "10".to_string())));
+        assert!(parse_override("expansion_2").is_err());
+        assert!(parse_override("=10").is_err());
+    }
+}
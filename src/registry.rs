@@ -0,0 +1,124 @@
+//! Registry of all solved days, so tools can dispatch to a day by its number.
+
+use std::fmt;
+
+use crate::days::day_01_trebuchet::Trebuchet;
+use crate::days::day_02_cube_conundrum::CubeConundrum;
+use crate::days::day_03_gear_ratios::GearRatios;
+use crate::days::day_04_scratchcards::Scratchcards;
+use crate::days::day_05_seed_fertilizer::SeedFertilizer;
+use crate::days::day_06_wait_for_it::WaitForIt;
+use crate::days::day_07_camel_cards::CamelCards;
+use crate::days::day_08_haunted_wasteland::HauntedWasteland;
+use crate::days::day_09_mirage_maintenance::MirageMaintenance;
+use crate::days::day_10_pipe_maze::PipeMaze;
+use crate::days::day_11_cosmic_expansion::CosmicExpansion;
+use crate::days::day_12_hot_springs::HotSprings;
+use crate::days::day_13_point_of_incidence::PointOfIncidence;
+use crate::params::ParamInfo;
+use crate::{ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Parsed input of some day, with the answers turned into strings.
+pub trait Puzzle {
+    fn solve(&self, part: Part) -> String;
+    fn explain(&self, disagreeing_only: bool) -> Option<String>;
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+impl<S: Solution> Puzzle for Parsed<S> {
+    fn solve(&self, part: Part) -> String {
+        match part {
+            Part::One => S::part_one(&self.0).to_string(),
+            Part::Two => S::part_two(&self.0).to_string(),
+        }
+    }
+
+    fn explain(&self, disagreeing_only: bool) -> Option<String> {
+        S::explain(&self.0, disagreeing_only)
+    }
+}
+
+fn parse<S>(input: &str) -> Result<Box<dyn Puzzle>, ParseError>
+    where S: Solution + 'static, S::Input: 'static {
+    Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+}
+
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    pub params: &'static [&'static dyn ParamInfo],
+    parse: fn(&str) -> Result<Box<dyn Puzzle>, ParseError>,
+}
+
+impl Day {
+    const fn new<S>(number: u8, title: &'static str) -> Day
+        where S: Solution + 'static, S::Input: 'static {
+        Day { number, title, params: S::PARAMS, parse: parse::<S> }
+    }
+
+    /// Name shared by the day's module and its input files, e.g. `day_07_camel_cards`.
+    pub fn name(&self) -> String {
+        format!("day_{:02}_{}", self.number, self.title)
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Puzzle>, ParseError> {
+        (self.parse)(input)
+    }
+}
+
+pub static DAYS: &[Day] = &[
+    Day::new::<Trebuchet>(1, "trebuchet"),
+    Day::new::<CubeConundrum>(2, "cube_conundrum"),
+    Day::new::<GearRatios>(3, "gear_ratios"),
+    Day::new::<Scratchcards>(4, "scratchcards"),
+    Day::new::<SeedFertilizer>(5, "seed_fertilizer"),
+    Day::new::<WaitForIt>(6, "wait_for_it"),
+    Day::new::<CamelCards>(7, "camel_cards"),
+    Day::new::<HauntedWasteland>(8, "haunted_wasteland"),
+    Day::new::<MirageMaintenance>(9, "mirage_maintenance"),
+    Day::new::<PipeMaze>(10, "pipe_maze"),
+    Day::new::<CosmicExpansion>(11, "cosmic_expansion"),
+    Day::new::<HotSprings>(12, "hot_springs"),
+    Day::new::<PointOfIncidence>(13, "point_of_incidence"),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_day() {
+        assert_eq!(find_day(7).unwrap().name(), "day_07_camel_cards");
+        assert_eq!(find_day(13).unwrap().name(), "day_13_point_of_incidence");
+        assert!(find_day(25).is_none());
+    }
+
+    #[test]
+    fn test_days_are_sorted_and_unique() {
+        assert!(DAYS.windows(2).all(|w| w[0].number < w[1].number));
+    }
+}
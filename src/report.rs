@@ -0,0 +1,282 @@
+//! Results of running a day on one input: its answers, how long each phase took, and a checksum of the input,
+//! so runs can be told apart when the input changes.
+//!
+//! In JSON every day is one object on a line of its own:
+//!
+//! ```text
+//! {"answers":{"1":"250370104"},"day":7,"error":null,"input":"inputs/day_07_camel_cards.txt","name":"day_07_camel_cards","sha256":"…","timings_ns":{"parse":81234,"part 1":95312}}
+//! ```
+
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+use crate::answers::Answers;
+use crate::bench::{format_duration, Phase, Timings};
+use crate::cancel::{CancelToken, Cancelled, Watchdog};
+use crate::input::read_input;
+use crate::params::Overrides;
+use crate::registry::{Day, Part};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub day: u8,
+    pub name: String,
+    pub input: PathBuf,
+    /// SHA-256 of the input in hex; None if it cannot be read
+    pub checksum: Option<String>,
+    pub answers: Vec<(Part, String)>,
+    /// Single runs, unlike the medians of `aoc bench`
+    pub timings: Timings,
+    /// Why the day has no answers, or not all of them: an unreadable input, a parse error, a panic or a timeout
+    pub error: Option<String>,
+}
+
+impl Report {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let answers: serde_json::Map<String, serde_json::Value> = self.answers.iter()
+            .map(|(part, answer)| (part.to_string(), answer.as_str().into()))
+            .collect();
+        let timings: serde_json::Map<String, serde_json::Value> = self.timings.0.iter()
+            .map(|(phase, duration)| (phase.to_string(), (duration.as_nanos() as u64).into()))
+            .collect();
+
+        json!({
+            "day": self.day,
+            "name": self.name,
+            "input": self.input.display().to_string(),
+            "sha256": self.checksum,
+            "answers": answers,
+            "timings_ns": timings,
+            "error": self.error,
+        })
+    }
+
+    /// Reads a report printed by `--format json`, e.g. by another build of the runner.
+    pub fn from_json(json: &serde_json::Value) -> Result<Report, String> {
+        let field = |name: &str| json.get(name).ok_or_else(|| format!("missing {}", name));
+        let text = |name: &str| field(name)?.as_str().map(|s| s.to_string()).ok_or_else(|| format!("{} is not a string", name));
+        let optional_text = |name: &str| match field(name)? {
+            serde_json::Value::Null => Ok(None),
+            _ => text(name).map(Some),
+        };
+        let object = |name: &str| field(name)?.as_object().ok_or_else(|| format!("{} is not an object", name));
+
+        let mut answers = vec![];
+        for (part, answer) in object("answers")? {
+            let part = match part.as_str() {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(format!("Unknown part: {}", part)),
+            };
+            answers.push((part, answer.as_str().ok_or("answer is not a string")?.to_string()));
+        }
+
+        let mut timings = Timings::default();
+        for (phase, nanos) in object("timings_ns")? {
+            let nanos = nanos.as_u64().ok_or("timing is not a number")?;
+            timings.0.insert(phase.parse()?, Duration::from_nanos(nanos));
+        }
+
+        Ok(Report {
+            day: field("day")?.as_u64().and_then(|day| u8::try_from(day).ok()).ok_or("day is not a number")?,
+            name: text("name")?,
+            input: PathBuf::from(text("input")?),
+            checksum: optional_text("sha256")?,
+            answers,
+            timings,
+            error: optional_text("error")?,
+        })
+    }
+}
+
+pub fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Solves the given parts of a day with the input at `path`, or the standard input for `-`, timing parsing and each part once.
+/// Panics of the solver are caught and reported as errors, and so are parsing and parts cancelled after `timeout`,
+/// which stops them where they call `cancel::checkpoint()`; the answers of the other parts are kept. Parameters of the day are overridden by `overrides`.
+pub fn run_day(day: &Day, path: &Path, parts: &[Part], timeout: Option<Duration>, overrides: &Overrides) -> Report {
+    let mut report = Report {
+        day: day.number,
+        name: day.name(),
+        input: path.to_path_buf(),
+        checksum: None,
+        answers: vec![],
+        timings: Timings::default(),
+        error: None,
+    };
+
+    let contents = match read_input(path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            report.error = Some(format!("Cannot read {}: {}", path.display(), e));
+            return report;
+        }
+    };
+    report.checksum = Some(sha256_hex(contents.as_bytes()));
+
+    let timed_out = |what: String| format!("{} {} timed out after {} s", day.name(), what,
+                                           timeout.unwrap_or_default().as_secs_f64());
+
+    let start = Instant::now();
+    let puzzle = match run_cancellable(timeout, || overrides.scope(|| day.parse(&contents))) {
+        Ok(Ok(puzzle)) => puzzle,
+        Ok(Err(e)) => {
+            report.error = Some(e.with_file(path).to_string());
+            return report;
+        }
+        Err(payload) => {
+            report.error = Some(match payload.is::<Cancelled>() {
+                true => timed_out("[parse]".to_string()),
+                false => format!("{} failed", day.name()),
+            });
+            return report;
+        }
+    };
+    report.timings.0.insert(Phase::Parse, start.elapsed());
+
+    for &part in parts {
+        let start = Instant::now();
+        match run_cancellable(timeout, || overrides.scope(|| puzzle.solve(part))) {
+            Ok(answer) => {
+                report.timings.0.insert(Phase::Part(part), start.elapsed());
+                report.answers.push((part, answer));
+            }
+            Err(payload) => {
+                let error = match payload.is::<Cancelled>() {
+                    true => timed_out(format!("[part {}]", part)),
+                    false => format!("{} [part {}] failed", day.name(), part),
+                };
+                report.error.get_or_insert(error);
+            }
+        }
+    }
+    report
+}
+
+/// Runs `f` with a token that is cancelled once `timeout` has passed, catching its panics.
+fn run_cancellable<R>(timeout: Option<Duration>, f: impl FnOnce() -> R) -> thread::Result<R> {
+    let token = CancelToken::new();
+    let _watchdog = timeout.map(|budget| Watchdog::start(token.clone(), budget));
+    panic::catch_unwind(AssertUnwindSafe(|| token.scope(f)))
+}
+
+/// `*` for an answer that matches the recorded one, `!` for one that differs, `?` if none is recorded,
+/// and `.` for a part that was not solved.
+fn star(report: &Report, expected: &Answers, part: Part) -> char {
+    let Some((_, answer)) = report.answers.iter().find(|&&(p, _)| p == part) else {
+        return '.';
+    };
+    match expected.get(&report.input.display().to_string(), part) {
+        Some(recorded) if recorded == answer => '*',
+        Some(_) => '!',
+        None => '?',
+    }
+}
+
+/// Renders reports as a calendar, one day per row with its stars, answers and runtime, and the totals.
+/// `wall_clock` is how long the whole run took, which is less than the sum of the days when they run in parallel.
+pub fn format_calendar(reports: &[Report], expected: &Answers, wall_clock: Duration) -> String {
+    let mut table = format!("{:>3}  {:<26}{:<7}{:<18}{:<18}{:>10}\n", "Day", "Title", "Stars", "Part 1", "Part 2", "Time");
+
+    let mut stars = 0;
+    let mut total = Duration::ZERO;
+    for report in reports {
+        let title = report.name.splitn(3, '_').nth(2).unwrap_or(&report.name);
+        table += &format!("{:>3}  {:<26}", report.day, title);
+        let day_stars: String = Part::BOTH.iter().map(|&part| star(report, expected, part)).collect();
+        stars += day_stars.matches('*').count();
+        table += &format!("{:<7}", day_stars);
+        for part in Part::BOTH {
+            let answer = report.answers.iter().find(|&&(p, _)| p == part).map_or("-", |(_, a)| a.as_str());
+            table += &format!("{:<18}", answer);
+        }
+        table += &format!("{:>10}", format_duration(report.timings.total()));
+        if let Some(e) = &report.error {
+            table += &format!("  FAILED: {}", e.lines().next().unwrap_or_default());
+        }
+        table += "\n";
+        total += report.timings.total();
+    }
+
+    let failed = reports.iter().filter(|r| !r.is_ok()).count();
+    table += &format!("{:>3}  {:<26}{:<43}{:>10}\n", "", "Total", format!("{} stars, {} failed", stars, failed),
+                      format_duration(total));
+    table += &format!("{:>3}  {:<26}{:<43}{:>10}\n", "", "Wall clock", "", format_duration(wall_clock));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(sha256_hex(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+
+    #[test]
+    fn test_to_json() {
+        let report = Report {
+            day: 7,
+            name: "day_07_camel_cards".to_string(),
+            input: PathBuf::from("inputs/day_07_camel_cards.txt"),
+            checksum: Some("ab12".to_string()),
+            answers: vec![(Part::One, "250370104".to_string())],
+            timings: Timings([(Phase::Parse, Duration::from_nanos(81)), (Phase::Part(Part::One), Duration::from_nanos(95))].into()),
+            error: None,
+        };
+        assert_eq!(report.to_json().to_string(),
+                   "{\"answers\":{\"1\":\"250370104\"},\"day\":7,\"error\":null,\"input\":\"inputs/day_07_camel_cards.txt\",\
+                    \"name\":\"day_07_camel_cards\",\"sha256\":\"ab12\",\"timings_ns\":{\"parse\":81,\"part 1\":95}}");
+        assert_eq!(Report::from_json(&report.to_json()), Ok(report));
+        assert!(Report::from_json(&json!({"day": 7})).is_err());
+    }
+
+    #[test]
+    fn test_format_calendar() {
+        let report = |day: u8, title: &str, answers: &[&str], error: Option<&str>| Report {
+            day,
+            name: format!("day_{:02}_{}", day, title),
+            input: PathBuf::from(format!("inputs/day_{:02}_{}.txt", day, title)),
+            checksum: None,
+            answers: Part::BOTH.into_iter().zip(answers.iter().map(|a| a.to_string())).collect(),
+            timings: match answers.is_empty() {
+                true => Timings::default(),
+                false => Timings([(Phase::Parse, Duration::from_millis(2))].into()),
+            },
+            error: error.map(|e| e.to_string()),
+        };
+        let reports = [
+            report(1, "trebuchet", &["142", "281"], None),
+            report(2, "cube_conundrum", &["8"], None),
+            report(12, "hot_springs", &["7460"], Some("day_12_hot_springs [part 2] timed out after 10 s")),
+            report(14, "parabolic_reflector_dish", &[], Some("Cannot read inputs/day_14_parabolic_reflector_dish.txt")),
+        ];
+        let mut expected = Answers::default();
+        expected.record("inputs/day_01_trebuchet.txt", Part::One, "142");
+        expected.record("inputs/day_01_trebuchet.txt", Part::Two, "280");
+
+        let table = format_calendar(&reports, &expected, Duration::from_millis(7));
+        let lines: Vec<&str> = table.lines().map(|l| l.trim_end()).collect();
+        assert_eq!(lines, [
+            "Day  Title                     Stars  Part 1            Part 2                  Time",
+            "  1  trebuchet                 *!     142               281                  2.00 ms",
+            "  2  cube_conundrum            ?.     8                 -                    2.00 ms",
+            " 12  hot_springs               ?.     7460              -                    2.00 ms  FAILED: day_12_hot_springs [part 2] timed out after 10 s",
+            " 14  parabolic_reflector_dish  ..     -                 -                       0 ns  FAILED: Cannot read inputs/day_14_parabolic_reflector_dish.txt",
+            "     Total                     1 stars, 2 failed                             6.00 ms",
+            "     Wall clock                                                              7.00 ms",
+        ]);
+    }
+}
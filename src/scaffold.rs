@@ -0,0 +1,215 @@
+//! Scaffolding for a new day: its module, empty input files, and its entries in `days/mod.rs` and the registry.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::input::InputKind;
+
+const DAYS_MOD: &str = "src/days/mod.rs";
+const REGISTRY: &str = "src/registry.rs";
+
+/// Name of the solution type, e.g. `CamelCards` for `camel_cards`.
+fn type_name(title: &str) -> String {
+    title.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn module_source(number: u8, title: &str) -> String {
+    let name = type_name(title);
+    format!(r#"//! Solution to an Advent of Code problem, day {number:02}, 2023
+//! https://adventofcode.com/2023/day/{number:02}
+
+use crate::{{ParseError, Solution}};
+
+pub struct {name};
+
+impl Solution for {name} {{
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        Ok(input.lines().map(|l| l.to_string()).collect())
+    }}
+
+    fn part_one(_lines: &Self::Input) -> u64 {{
+        0
+    }}
+
+    fn part_two(_lines: &Self::Input) -> u64 {{
+        0
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../test_inputs/day_{number:02}_{title}.txt");
+
+    #[test]
+    fn test_example() {{
+        let input = {name}::parse(EXAMPLE).unwrap();
+        // Expected answers for the example, from the puzzle text
+        assert_eq!({name}::part_one(&input), 0);
+        assert_eq!({name}::part_two(&input), 0);
+    }}
+}}
+"#)
+}
+
+/// Inserts `line` among the lines starting with `prefix`, keeping them sorted.
+/// Returns None if there are no such lines to insert it among.
+fn insert_sorted(source: &str, prefix: &str, line: &str) -> Option<String> {
+    let lines: Vec<&str> = source.lines().collect();
+    let first = lines.iter().position(|l| l.starts_with(prefix))?;
+    let last = lines.iter().rposition(|l| l.starts_with(prefix))?;
+    let at = (first..=last).find(|&i| lines[i] > line).unwrap_or(last + 1);
+
+    let mut result: Vec<&str> = lines[..at].to_vec();
+    result.push(line);
+    result.extend(&lines[at..]);
+    Some(result.join("\n") + "\n")
+}
+
+/// Adds the day to the source of `days/mod.rs`.
+fn add_module(days_mod: &str, module: &str) -> Result<String, String> {
+    let line = format!("pub mod {};", module);
+    if days_mod.lines().any(|l| l == line) {
+        return Err(format!("{} is declared already", module));
+    }
+    match days_mod.trim().is_empty() {
+        true => Ok(line + "\n"),
+        false => insert_sorted(days_mod, "pub mod ", &line).ok_or_else(|| "No modules in days/mod.rs".to_string()),
+    }
+}
+
+/// Line numbers and day numbers of the entries in `DAYS`, like `Day::new::<CamelCards>(7, "camel_cards"),`.
+fn registry_entries(lines: &[&str]) -> Vec<(usize, u8)> {
+    lines.iter().enumerate()
+        .filter_map(|(i, l)| {
+            let rest = l.trim().strip_prefix("Day::new::<")?;
+            Some((i, rest.split_once(">(")?.1.split_once(',')?.0.parse().ok()?))
+        })
+        .collect()
+}
+
+/// Adds the day to the source of the registry: its import, and its entry in `DAYS`.
+fn add_to_registry(registry: &str, number: u8, title: &str) -> Result<String, String> {
+    let lines: Vec<&str> = registry.lines().collect();
+    let entries = registry_entries(&lines);
+    if entries.iter().any(|&(_, n)| n == number) {
+        return Err(format!("Day {} is registered already", number));
+    }
+
+    // Entries are sorted by number, not by name
+    let last = entries.last().ok_or_else(|| "No days in the registry".to_string())?.0;
+    let at = entries.iter().find(|&&(_, n)| n > number).map_or(last + 1, |&(i, _)| i);
+    let entry = format!("    Day::new::<{}>({}, \"{}\"),", type_name(title), number, title);
+    let mut with_entry: Vec<&str> = lines[..at].to_vec();
+    with_entry.push(&entry);
+    with_entry.extend(&lines[at..]);
+
+    let import = format!("use crate::days::day_{:02}_{}::{};", number, title, type_name(title));
+    insert_sorted(&(with_entry.join("\n") + "\n"), "use crate::days::", &import)
+        .ok_or_else(|| "No day imports in the registry".to_string())
+}
+
+/// Creates a new day under `root`, the directory of the crate. Returns the files created or changed.
+/// Nothing is written unless all of it can be done, and existing days are never overwritten.
+pub fn new_day(root: &Path, number: u8, title: &str) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&number) {
+        return Err(format!("Day {} is not in 1..=25", number));
+    }
+    let valid_title = title.starts_with(|c: char| c.is_ascii_lowercase())
+        && title.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid_title {
+        return Err(format!("Title {} is not in snake_case, e.g. camel_cards", title));
+    }
+
+    let module = format!("day_{:02}_{}", number, title);
+    let module_path = root.join("src/days").join(format!("{}.rs", module));
+    if module_path.exists() {
+        return Err(format!("{} exists already", module_path.display()));
+    }
+
+    let read = |path: &str| fs::read_to_string(root.join(path)).map_err(|e| format!("Cannot read {}: {}", path, e));
+    let days_mod = add_module(&read(DAYS_MOD)?, &module)?;
+    let registry = add_to_registry(&read(REGISTRY)?, number, title)?;
+
+    let write = |path: &Path, contents: &str| fs::write(path, contents).map_err(|e| format!("Cannot write {}: {}", path.display(), e));
+    write(&module_path, &module_source(number, title))?;
+    write(&root.join(DAYS_MOD), &days_mod)?;
+    write(&root.join(REGISTRY), &registry)?;
+    let mut changed = vec![module_path, root.join(DAYS_MOD), root.join(REGISTRY)];
+
+    // Inputs may have been fetched already
+    for kind in [InputKind::Example, InputKind::Real] {
+        let directory = root.join(kind.directory());
+        let input_path = directory.join(format!("{}.txt", module));
+        if !input_path.exists() {
+            fs::create_dir_all(&directory).map_err(|e| format!("Cannot create {}: {}", directory.display(), e))?;
+            write(&input_path, "")?;
+            changed.push(input_path);
+        }
+    }
+
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_name() {
+        assert_eq!(type_name("camel_cards"), "CamelCards");
+        assert_eq!(type_name("trebuchet"), "Trebuchet");
+    }
+
+    #[test]
+    fn test_add_module() {
+        let days_mod = "pub mod day_01_trebuchet;\npub mod day_13_point_of_incidence;\n";
+        assert_eq!(add_module(days_mod, "day_07_camel_cards"),
+                   Ok("pub mod day_01_trebuchet;\npub mod day_07_camel_cards;\npub mod day_13_point_of_incidence;\n".to_string()));
+        assert!(add_module(days_mod, "day_01_trebuchet").is_err());
+    }
+
+    #[test]
+    fn test_add_to_registry() {
+        let registry = "use crate::days::day_01_trebuchet::Trebuchet;\n\
+                        use crate::days::day_10_pipe_maze::PipeMaze;\n\
+                        use crate::{ParseError, Solution};\n\
+                        \n\
+                        pub static DAYS: &[Day] = &[\n    \
+                            Day::new::<Trebuchet>(1, \"trebuchet\"),\n    \
+                            Day::new::<PipeMaze>(10, \"pipe_maze\"),\n\
+                        ];\n";
+
+        assert_eq!(add_to_registry(registry, 2, "cube_conundrum").unwrap(),
+                   "use crate::days::day_01_trebuchet::Trebuchet;\n\
+                    use crate::days::day_02_cube_conundrum::CubeConundrum;\n\
+                    use crate::days::day_10_pipe_maze::PipeMaze;\n\
+                    use crate::{ParseError, Solution};\n\
+                    \n\
+                    pub static DAYS: &[Day] = &[\n    \
+                        Day::new::<Trebuchet>(1, \"trebuchet\"),\n    \
+                        Day::new::<CubeConundrum>(2, \"cube_conundrum\"),\n    \
+                        Day::new::<PipeMaze>(10, \"pipe_maze\"),\n\
+                    ];\n");
+
+        let with_last = add_to_registry(registry, 14, "parabolic_reflector_dish").unwrap();
+        assert!(with_last.contains("    Day::new::<PipeMaze>(10, \"pipe_maze\"),\n    Day::new::<ParabolicReflectorDish>(14, \"parabolic_reflector_dish\"),\n];"));
+        assert!(with_last.contains("use crate::days::day_14_parabolic_reflector_dish::ParabolicReflectorDish;\nuse crate::{"));
+
+        assert_eq!(add_to_registry(registry, 10, "other"), Err("Day 10 is registered already".to_string()));
+    }
+}
@@ -0,0 +1,26 @@
+use std::fmt::Display;
+
+use crate::params::ParamInfo;
+use crate::ParseError;
+
+/// A solver for one day of the calendar.
+///
+/// The puzzle input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Constants of the puzzle that can be overridden to try variants of it, read with `Param::get`.
+    const PARAMS: &'static [&'static dyn ParamInfo] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Self::Answer1;
+    fn part_two(input: &Self::Input) -> Self::Answer2;
+
+    /// A report on the input beyond the answers, to see how they come about, printed by `aoc explain`.
+    /// With `disagreeing_only`, only about what the parts disagree on, for days that tell. None for days without one.
+    fn explain(_input: &Self::Input, _disagreeing_only: bool) -> Option<String> {
+        None
+    }
+}
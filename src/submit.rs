@@ -0,0 +1,349 @@
+//! Submitting answers, and remembering the rejected ones in `rejected.txt` so none is submitted twice.
+//!
+//! Every non-empty line that is not a `#` comment holds the day, part, verdict and answer:
+//!
+//! ```text
+//! day_07_camel_cards 1 too-high 250370200
+//! ```
+//!
+//! After the site asked to wait before answering again, a `wait-until` line holds the Unix time when that is over,
+//! and no answer is submitted before, also in later runs.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::client::{Client, ClientError};
+use crate::registry::Part;
+
+pub const REJECTED_FILE: &str = "rejected.txt";
+
+/// What the site told about a wrong answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    /// Wrong, without telling how
+    Wrong,
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too-high"),
+            Hint::TooLow => write!(f, "too-low"),
+            Hint::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+impl FromStr for Hint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "too-high" => Ok(Hint::TooHigh),
+            "too-low" => Ok(Hint::TooLow),
+            "wrong" => Ok(Hint::Wrong),
+            _ => Err(format!("Unknown verdict: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect(Hint),
+    /// An answer was submitted too recently; try again after this long
+    Wait(Duration),
+    /// The part is solved already, or part 1 is not solved yet
+    WrongLevel,
+    /// A response this code does not understand, with its text
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Reads the verdict from the HTML page the site responds with.
+    pub fn parse(html: &str) -> Verdict {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("too high") {
+                Hint::TooHigh
+            } else if text.contains("too low") {
+                Hint::TooLow
+            } else {
+                Hint::Wrong
+            };
+            Verdict::Incorrect(hint)
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::Wait(parse_wait(&text).unwrap_or(Duration::from_secs(60)))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::Incorrect(Hint::TooHigh) => write!(f, "That's not the right answer, it's too high."),
+            Verdict::Incorrect(Hint::TooLow) => write!(f, "That's not the right answer, it's too low."),
+            Verdict::Incorrect(Hint::Wrong) => write!(f, "That's not the right answer."),
+            Verdict::Wait(d) => write!(f, "An answer was submitted too recently, wait {} s.", d.as_secs()),
+            Verdict::WrongLevel => write!(f, "This part is solved already or not unlocked yet."),
+            Verdict::Unknown(text) => write!(f, "Unexpected response: {}", text),
+        }
+    }
+}
+
+/// Text of the `<article>` holding the message, without tags; the whole page if there is none.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads "you have 1m 3s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let end = text.find(" left to wait")?;
+    let start = text[..end].rfind("have ")? + "have ".len();
+    let mut seconds = 0;
+    for amount in text[start..end].split_whitespace() {
+        let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Answers the site rejected, by day name and part.
+#[derive(Debug, Default, PartialEq)]
+pub struct Rejected {
+    entries: BTreeMap<(String, Part), Vec<(Hint, String)>>,
+    /// When the site accepts answers again, after it asked to wait
+    wait_until: Option<SystemTime>,
+}
+
+impl Rejected {
+    /// Loads rejected answers from a file. A missing file means none were rejected yet.
+    pub fn load(path: &Path) -> io::Result<Rejected> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse().map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Rejected::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn record(&mut self, day: &str, part: Part, hint: Hint, answer: &str) {
+        self.entries.entry((day.to_string(), part)).or_default().push((hint, answer.to_string()));
+    }
+
+    /// Remembers that the site accepts no answers before `until`.
+    pub fn wait(&mut self, until: SystemTime) {
+        self.wait_until = Some(until);
+    }
+
+    /// How long after `now` the site still accepts no answers, if it asked to wait.
+    pub fn wait_left(&self, now: SystemTime) -> Option<Duration> {
+        self.wait_until?.duration_since(now).ok().filter(|left| !left.is_zero())
+    }
+
+    /// Why `answer` is known to be wrong without submitting it, if it is.
+    pub fn reason_to_skip(&self, day: &str, part: Part, answer: &str) -> Option<String> {
+        let rejected = self.entries.get(&(day.to_string(), part))?;
+        if let Some((hint, _)) = rejected.iter().find(|(_, a)| a == answer) {
+            return Some(format!("{} was rejected already as {}", answer, hint));
+        }
+
+        // Numbers beyond a bound the site gave are wrong as well
+        let number: i128 = answer.parse().ok()?;
+        rejected.iter().find_map(|(hint, a)| {
+            let bound: i128 = a.parse().ok()?;
+            match hint {
+                Hint::TooHigh if number > bound => Some(format!("{} is above {}, which is too high", answer, bound)),
+                Hint::TooLow if number < bound => Some(format!("{} is below {}, which is too low", answer, bound)),
+                _ => None,
+            }
+        })
+    }
+}
+
+impl FromStr for Rejected {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rejected = Rejected::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.splitn(4, ' ').collect();
+            if fields[0] == "wait-until" {
+                let seconds: u64 = fields.get(1).and_then(|s| s.parse().ok())
+                    .ok_or_else(|| format!("line {}: expected `wait-until <unix time>`", i + 1))?;
+                rejected.wait(UNIX_EPOCH + Duration::from_secs(seconds));
+                continue;
+            }
+            let part = match fields.get(1) {
+                Some(&"1") => Part::One,
+                Some(&"2") => Part::Two,
+                _ => return Err(format!("line {}: expected `<day> <part> <verdict> <answer>`", i + 1)),
+            };
+            let hint = match fields.get(2) {
+                Some(hint) => hint.parse().map_err(|e| format!("line {}: {}", i + 1, e))?,
+                None => return Err(format!("line {}: missing verdict", i + 1)),
+            };
+            match fields.get(3) {
+                Some(answer) => rejected.record(fields[0], part, hint, answer.trim()),
+                None => return Err(format!("line {}: missing answer", i + 1)),
+            }
+        }
+        Ok(rejected)
+    }
+}
+
+impl fmt::Display for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# <day> <part> <verdict> <answer>")?;
+        if let Some(until) = self.wait_until {
+            writeln!(f, "wait-until {}", until.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs())?;
+        }
+        for ((day, part), answers) in &self.entries {
+            for (hint, answer) in answers {
+                writeln!(f, "{} {} {} {}", day, part, hint, answer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    /// Not sent, because the answer is known to be wrong or the site asked to wait
+    Skipped(String),
+    Sent(Verdict),
+}
+
+/// Submits an answer unless it is known to be wrong or the site asked to wait, and remembers it if the site
+/// rejects it, or how long to wait if it asks to.
+pub fn submit_answer(client: &mut Client, rejected: &mut Rejected, day: u8, day_name: &str, part: Part, answer: &str)
+                     -> Result<Submission, ClientError> {
+    if let Some(left) = rejected.wait_left(SystemTime::now()) {
+        return Ok(Submission::Skipped(format!("the site asked to wait, {} s left", left.as_secs_f64().ceil())));
+    }
+    if let Some(reason) = rejected.reason_to_skip(day_name, part, answer) {
+        return Ok(Submission::Skipped(reason));
+    }
+
+    let verdict = Verdict::parse(&client.answer(day, part, answer)?);
+    match verdict {
+        Verdict::Incorrect(hint) => rejected.record(day_name, part, hint, answer),
+        Verdict::Wait(left) => rejected.wait(SystemTime::now() + left),
+        _ => {}
+    }
+    Ok(Submission::Sent(verdict))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{}</p></article></main></body></html>", message)
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(Verdict::parse(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")),
+                   Verdict::Correct);
+        assert_eq!(Verdict::parse(&page("That's not the right answer; your answer is too high.  If you're stuck, ...")),
+                   Verdict::Incorrect(Hint::TooHigh));
+        assert_eq!(Verdict::parse(&page("That's not the right answer; your answer is too low.")),
+                   Verdict::Incorrect(Hint::TooLow));
+        assert_eq!(Verdict::parse(&page("That's not the right answer.  If you're stuck, ...")),
+                   Verdict::Incorrect(Hint::Wrong));
+        assert_eq!(Verdict::parse(&page("You gave an answer too recently; you have to wait after submitting an answer \
+                                         before trying again.  You have 1m 3s left to wait. [Return to Day 7]")),
+                   Verdict::Wait(Duration::from_secs(63)));
+        assert_eq!(Verdict::parse(&page("You don't seem to be solving the right level.  Did you already complete it?")),
+                   Verdict::WrongLevel);
+        assert_eq!(Verdict::parse("<html>Oops</html>"), Verdict::Unknown("Oops".to_string()));
+    }
+
+    #[test]
+    fn test_reason_to_skip() {
+        let mut rejected = Rejected::default();
+        rejected.record("day_07_camel_cards", Part::One, Hint::TooHigh, "300");
+        rejected.record("day_07_camel_cards", Part::One, Hint::TooLow, "100");
+        rejected.record("day_07_camel_cards", Part::One, Hint::Wrong, "200");
+
+        assert!(rejected.reason_to_skip("day_07_camel_cards", Part::One, "300").is_some());
+        assert!(rejected.reason_to_skip("day_07_camel_cards", Part::One, "301").is_some());
+        assert!(rejected.reason_to_skip("day_07_camel_cards", Part::One, "99").is_some());
+        assert!(rejected.reason_to_skip("day_07_camel_cards", Part::One, "200").is_some());
+        assert_eq!(rejected.reason_to_skip("day_07_camel_cards", Part::One, "250"), None);
+        assert_eq!(rejected.reason_to_skip("day_07_camel_cards", Part::Two, "300"), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut rejected = Rejected::default();
+        rejected.record("day_07_camel_cards", Part::Two, Hint::Wrong, "abc def");
+        rejected.record("day_07_camel_cards", Part::One, Hint::TooHigh, "300");
+
+        let text = rejected.to_string();
+        assert_eq!(text, "# <day> <part> <verdict> <answer>\n\
+                          day_07_camel_cards 1 too-high 300\n\
+                          day_07_camel_cards 2 wrong abc def\n");
+        assert_eq!(text.parse(), Ok(rejected));
+    }
+
+    #[test]
+    fn test_wait() {
+        let mut rejected = Rejected::default();
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        assert_eq!(rejected.wait_left(now), None);
+
+        rejected.wait(now + Duration::from_secs(63));
+        assert_eq!(rejected.wait_left(now), Some(Duration::from_secs(63)));
+        assert_eq!(rejected.wait_left(now + Duration::from_secs(63)), None);
+
+        let text = rejected.to_string();
+        assert_eq!(text, "# <day> <part> <verdict> <answer>\nwait-until 1700000063\n");
+        assert_eq!(text.parse(), Ok(rejected));
+        assert!("wait-until soon".parse::<Rejected>().is_err());
+    }
+}
@@ -0,0 +1,87 @@
+//! Watching the files of a day, to re-run it whenever its source or one of its inputs changes.
+//!
+//! Files are polled for their modification times, which needs nothing from the platform.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::examples::part_two_path;
+use crate::input::InputKind;
+use crate::registry::{Day, Part};
+
+/// The module of a day and its input files, also those that do not exist yet.
+pub fn watched_files(day: &Day) -> Vec<PathBuf> {
+    let example = InputKind::Example.path(day);
+    vec![
+        PathBuf::from("src/days").join(format!("{}.rs", day.name())),
+        part_two_path(&example),
+        example,
+        InputKind::Real.path(day),
+    ]
+}
+
+/// Modification times of files; None for a file that does not exist.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, Option<SystemTime>>);
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Snapshot {
+        Snapshot(paths.iter()
+            .map(|path| (path.clone(), fs::metadata(path).and_then(|m| m.modified()).ok()))
+            .collect())
+    }
+
+    /// Files changed, created or removed since `earlier`. All of them if `earlier` is empty.
+    pub fn changed_since(&self, earlier: &Snapshot) -> Vec<PathBuf> {
+        self.0.iter()
+            .filter(|&(path, modified)| earlier.0.get(path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+}
+
+/// How the answers of a run differ from those of the previous one, a line per part.
+pub fn diff_answers(previous: Option<&[(Part, String)]>, current: &[(Part, String)]) -> Vec<String> {
+    current.iter()
+        .map(|(part, answer)| {
+            let before = previous.and_then(|answers| answers.iter().find(|&&(p, _)| p == *part));
+            match before {
+                None => format!("[part {}] {}", part, answer),
+                Some((_, before)) if before == answer => format!("[part {}] {} (unchanged)", part, answer),
+                Some((_, before)) => format!("[part {}] {} (was {})", part, answer, before),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed_since() {
+        let dir = std::env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (file, missing) = (dir.join("day.rs"), dir.join("missing.txt"));
+        fs::write(&file, "").unwrap();
+        let paths = [file.clone(), missing.clone()];
+
+        let first = Snapshot::take(&paths);
+        assert_eq!(first.changed_since(&Snapshot::default()), paths);
+        assert_eq!(Snapshot::take(&paths).changed_since(&first), Vec::<PathBuf>::new());
+
+        fs::write(&missing, "created").unwrap();
+        assert_eq!(Snapshot::take(&paths).changed_since(&first), [missing]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let answers = |a: &str, b: &str| vec![(Part::One, a.to_string()), (Part::Two, b.to_string())];
+        assert_eq!(diff_answers(None, &answers("6440", "0")), ["[part 1] 6440", "[part 2] 0"]);
+        assert_eq!(diff_answers(Some(&answers("6440", "0")), &answers("6440", "5905")),
+                   ["[part 1] 6440 (unchanged)", "[part 2] 5905 (was 0)"]);
+    }
+}
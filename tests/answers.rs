@@ -0,0 +1,63 @@
+//! Runs every registered day on its inputs and compares the results with `answers.txt`.
+//! Parts without a recorded answer are skipped.
+
+use std::fs;
+use std::panic;
+use std::path::Path;
+
+use advent_of_code_2022::answers::{Answers, ANSWERS_FILE};
+use advent_of_code_2022::examples::part_two_path;
+use advent_of_code_2022::input::InputKind;
+use advent_of_code_2022::registry::{Part, DAYS};
+use advent_of_code_2022::ParseError;
+
+#[test]
+fn test_recorded_answers() {
+    let answers = Answers::load(Path::new(ANSWERS_FILE)).unwrap();
+    let mut mismatches = vec![];
+
+    for day in DAYS {
+        let example = InputKind::Example.path(day);
+        for path in [part_two_path(&example), example, InputKind::Real.path(day)] {
+            let input = path.display().to_string();
+            let expected = Part::BOTH.iter()
+                .filter_map(|&part| answers.get(&input, part).map(|answer| (part, answer)))
+                .collect::<Vec<_>>();
+            if expected.is_empty() {
+                continue;
+            }
+
+            let contents = match fs::read_to_string(&path) {
+                Ok(contents) => contents,
+                Err(e) => {
+                    mismatches.push(format!("{}: {}", input, e));
+                    continue;
+                }
+            };
+
+            let result = panic::catch_unwind(|| {
+                let puzzle = day.parse(&contents)?;
+                Ok::<_, ParseError>(expected.iter().map(|&(part, _)| puzzle.solve(part)).collect::<Vec<_>>())
+            });
+            let actual = match result {
+                Ok(Ok(actual)) => actual,
+                Ok(Err(e)) => {
+                    mismatches.push(e.with_file(&path).to_string());
+                    continue;
+                }
+                Err(_) => {
+                    mismatches.push(format!("{}: solver panicked", input));
+                    continue;
+                }
+            };
+
+            for ((part, expected), actual) in expected.into_iter().zip(actual) {
+                if expected != actual {
+                    mismatches.push(format!("{} [part {}]: expected {}, got {}", input, part, expected, actual));
+                }
+            }
+        }
+    }
+
+    assert!(mismatches.is_empty(), "Answers differ from {}:\n{}", ANSWERS_FILE, mismatches.join("\n"));
+}
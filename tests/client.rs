@@ -0,0 +1,191 @@
+//! Talks to a stub server standing in for the Advent of Code website.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use advent_of_code_2022::client::{fetch_input, Client, ClientError, Fetched};
+use advent_of_code_2022::registry::Part;
+use advent_of_code_2022::submit::{submit_answer, Hint, Rejected, Submission, Verdict};
+
+#[derive(Debug, Clone)]
+struct Request {
+    method: String,
+    path: String,
+    cookie: Option<String>,
+    body: String,
+}
+
+/// Answers connections in order with the given status and body, recording the requests.
+struct StubServer {
+    url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl StubServer {
+    fn start(responses: Vec<(u16, &'static str)>) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for (status, response) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut fields = request_line.split_whitespace();
+                let (method, path) = (fields.next().unwrap().to_string(), fields.next().unwrap().to_string());
+
+                let mut cookie = None;
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let (name, value) = header.split_once(": ").unwrap();
+                    match name.to_ascii_lowercase().as_str() {
+                        "cookie" => cookie = Some(value.to_string()),
+                        "content-length" => content_length = value.parse().unwrap(),
+                        _ => {}
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                let body = String::from_utf8(body).unwrap();
+                recorded.lock().unwrap().push(Request { method, path, cookie, body });
+
+                let mut stream = reader.into_inner();
+                write!(stream, "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                       status, response.len(), response).unwrap();
+            }
+        });
+
+        StubServer { url, requests }
+    }
+
+    fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// Fresh, empty directory for the files of one test.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn client(server: &StubServer) -> Client {
+    Client::new(&server.url, "secret").with_min_interval(Duration::ZERO)
+}
+
+#[test]
+fn test_fetch_downloads_once() {
+    let server = StubServer::start(vec![(200, "1abc2\npqr3stu8vwx\n")]);
+    let path = scratch_dir("fetch").join("inputs").join("day_01_trebuchet.txt");
+    let mut client = client(&server);
+
+    assert_eq!(fetch_input(&mut client, 1, &path).unwrap(), Fetched::Downloaded);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1abc2\npqr3stu8vwx\n");
+    assert_eq!(fetch_input(&mut client, 1, &path).unwrap(), Fetched::Cached);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2023/day/1/input");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+}
+
+#[test]
+fn test_fetch_replaces_empty_placeholder() {
+    let server = StubServer::start(vec![(200, "input")]);
+    let path = scratch_dir("placeholder").join("day_14_parabolic_reflector_dish.txt");
+    fs::write(&path, "").unwrap();
+
+    assert_eq!(fetch_input(&mut client(&server), 14, &path).unwrap(), Fetched::Downloaded);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "input");
+}
+
+#[test]
+fn test_fetch_error_keeps_no_file() {
+    let server = StubServer::start(vec![(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.")]);
+    let path = scratch_dir("error").join("day_02_cube_conundrum.txt");
+
+    match fetch_input(&mut client(&server), 2, &path) {
+        Err(ClientError::Http { status: 400, body }) => assert!(body.contains("Please log in")),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_requests_are_throttled() {
+    let server = StubServer::start(vec![(200, "a"), (200, "b")]);
+    let dir = scratch_dir("throttle");
+    let throttle_file = dir.join("last_request");
+    let min_interval = Duration::from_millis(300);
+
+    let mut first = Client::new(&server.url, "secret").with_min_interval(min_interval).with_throttle_file(&throttle_file);
+    fetch_input(&mut first, 1, &dir.join("a.txt")).unwrap();
+
+    // Another client, as in another run, still waits for the first one's request
+    let start = Instant::now();
+    let mut second = Client::new(&server.url, "secret").with_min_interval(min_interval).with_throttle_file(&throttle_file);
+    fetch_input(&mut second, 2, &dir.join("b.txt")).unwrap();
+    assert!(start.elapsed() >= min_interval - Duration::from_millis(50), "waited only {:?}", start.elapsed());
+}
+
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  \
+                        Please wait one minute before trying again.</p></article></main>";
+const WAIT: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer \
+                    before trying again.  You have 1m 3s left to wait.</p></article></main>";
+const CORRECT: &str = "<main><article><p>That's the right answer!  You are <span>one gold star</span> closer.</p></article></main>";
+
+#[test]
+fn test_submit_never_repeats_rejected_answers() {
+    let server = StubServer::start(vec![(200, TOO_HIGH), (200, CORRECT)]);
+    let mut client = client(&server);
+    let mut rejected = Rejected::default();
+    let mut submit = |answer| submit_answer(&mut client, &mut rejected, 7, "day_07_camel_cards", Part::One, answer).unwrap();
+
+    assert_eq!(submit("300"), Submission::Sent(Verdict::Incorrect(Hint::TooHigh)));
+    assert!(matches!(submit("300"), Submission::Skipped(_)));
+    assert!(matches!(submit("301"), Submission::Skipped(_)));
+    assert_eq!(submit("250"), Submission::Sent(Verdict::Correct));
+    assert_eq!(rejected.to_string(), "# <day> <part> <verdict> <answer>\nday_07_camel_cards 1 too-high 300\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2023/day/7/answer");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+    assert_eq!(requests[0].body, "level=1&answer=300");
+    assert_eq!(requests[1].body, "level=1&answer=250");
+}
+
+#[test]
+fn test_submit_waits_as_asked() {
+    let server = StubServer::start(vec![(200, WAIT)]);
+    let mut client = client(&server);
+    let mut rejected = Rejected::default();
+
+    let sent = submit_answer(&mut client, &mut rejected, 7, "day_07_camel_cards", Part::One, "300").unwrap();
+    assert_eq!(sent, Submission::Sent(Verdict::Wait(Duration::from_secs(63))));
+
+    // Also in a later run, no other answer is sent until the wait is over
+    let mut rejected: Rejected = rejected.to_string().parse().unwrap();
+    let skipped = submit_answer(&mut client, &mut rejected, 7, "day_07_camel_cards", Part::Two, "250").unwrap();
+    assert!(matches!(skipped, Submission::Skipped(reason) if reason.contains("wait")));
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,33 @@
+//! Creates a new day in a copy of the crate's registry.
+
+use std::fs;
+
+use advent_of_code_2022::scaffold::new_day;
+
+#[test]
+fn test_new_day() {
+    let root = std::env::temp_dir().join(format!("aoc_new_day_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src/days")).unwrap();
+    fs::create_dir_all(root.join("inputs")).unwrap();
+    fs::copy("src/days/mod.rs", root.join("src/days/mod.rs")).unwrap();
+    fs::copy("src/registry.rs", root.join("src/registry.rs")).unwrap();
+    fs::write(root.join("inputs/day_20_pulse_propagation.txt"), "broadcaster -> a\n").unwrap();
+
+    new_day(&root, 20, "pulse_propagation").unwrap();
+
+    let module = fs::read_to_string(root.join("src/days/day_20_pulse_propagation.rs")).unwrap();
+    assert!(module.contains("impl Solution for PulsePropagation {"));
+    assert!(fs::read_to_string(root.join("src/days/mod.rs")).unwrap().ends_with("pub mod day_20_pulse_propagation;\n"));
+    let registry = fs::read_to_string(root.join("src/registry.rs")).unwrap();
+    assert!(registry.contains("use crate::days::day_20_pulse_propagation::PulsePropagation;\n"));
+    assert!(registry.contains("    Day::new::<PulsePropagation>(20, \"pulse_propagation\"),\n];"));
+    assert_eq!(fs::read_to_string(root.join("test_inputs/day_20_pulse_propagation.txt")).unwrap(), "");
+    // A fetched input is kept
+    assert_eq!(fs::read_to_string(root.join("inputs/day_20_pulse_propagation.txt")).unwrap(), "broadcaster -> a\n");
+
+    // Existing days are never overwritten
+    assert!(new_day(&root, 20, "pulse_propagation").is_err());
+    assert!(new_day(&root, 7, "other_title").is_err());
+    assert_eq!(fs::read_to_string(root.join("src/days/day_20_pulse_propagation.rs")).unwrap(), module);
+}